@@ -0,0 +1,48 @@
+//! Compares rendering through a prepared frame with the frame iterator.
+//!
+//! Run with `cargo bench --bench prepare`.
+
+use std::hint::black_box;
+use std::time::Instant;
+use txtframe::*;
+
+const ROUNDS: u32 = 2_000;
+
+fn bench(name: &str, mut render: impl FnMut() -> String) {
+    let start = Instant::now();
+    let mut len = 0;
+    for _ in 0..ROUNDS {
+        len += black_box(render()).len();
+    }
+    let elapsed = start.elapsed();
+
+    println!(
+        "{name:<32} {:>10.2?} per frame ({} bytes)",
+        elapsed / ROUNDS,
+        len / ROUNDS as usize
+    );
+}
+
+fn main() {
+    let text_frame = TextFrame::new()
+        .frame_var(&FrameVar::Round)
+        .algn(Algn::Centr)
+        .expand_height(2)
+        .height(40);
+    let prepared = text_frame.prepare(80);
+    let sized = text_frame.clone().width(80);
+    let short = "A short message";
+    let lines = (0..30)
+        .map(|line| format!("line {line}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    for (name, text) in [("short", short), ("30 lines", lines.as_str())] {
+        bench(&format!("frame_iter, {name}"), || {
+            sized.frame_iter(black_box(text)).collect()
+        });
+        bench(&format!("prepare, {name}"), || {
+            prepared.render(black_box(text))
+        });
+    }
+}
@@ -1,10 +1,13 @@
 /// Text alignment within the frame.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Algn {
     /// Align left.
     #[default]
     Left,
     /// Align center.
+    #[cfg_attr(feature = "serde", serde(alias = "center"))]
     Centr,
     /// Align right.
     Right,
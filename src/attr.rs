@@ -0,0 +1,70 @@
+use core::ops::{BitOr, BitOrAssign};
+
+/// Set of text attributes.
+///
+/// Attributes are combined with `|`.
+///
+/// # Examples
+///
+/// ```
+/// # use txtframe::Attr;
+/// let attr = Attr::BOLD | Attr::UNDERLINE;
+///
+/// assert!(attr.contains(Attr::BOLD));
+/// assert!(!attr.contains(Attr::DIM));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Attr(u8);
+
+const ESCAPES: [&str; 6] = [
+    "\x1b[1m", "\x1b[2m", "\x1b[3m", "\x1b[4m", "\x1b[5m", "\x1b[7m",
+];
+
+impl Attr {
+    /// No attributes.
+    pub const NONE: Attr = Attr(0);
+    /// Bold or increased intensity.
+    pub const BOLD: Attr = Attr(1);
+    /// Faint or decreased intensity.
+    pub const DIM: Attr = Attr(1 << 1);
+    /// Italic.
+    pub const ITALIC: Attr = Attr(1 << 2);
+    /// Underline.
+    pub const UNDERLINE: Attr = Attr(1 << 3);
+    /// Slow blink.
+    pub const BLINK: Attr = Attr(1 << 4);
+    /// Swap foreground and background colors.
+    pub const REVERSE: Attr = Attr(1 << 5);
+
+    /// Checks if all attributes of `other` are set.
+    pub fn contains(self, other: Attr) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Checks if no attribute is set.
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub(crate) fn into_esc_iter(self) -> impl Iterator<Item = &'static str> + Clone {
+        ESCAPES
+            .iter()
+            .enumerate()
+            .filter(move |(idx, _)| self.0 & (1 << idx) != 0)
+            .map(|(_, esc)| *esc)
+    }
+}
+
+impl BitOr for Attr {
+    type Output = Attr;
+
+    fn bitor(self, rhs: Attr) -> Attr {
+        Attr(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for Attr {
+    fn bitor_assign(&mut self, rhs: Attr) {
+        self.0 |= rhs.0;
+    }
+}
@@ -0,0 +1,429 @@
+use crate::FrameVar;
+use core::fmt;
+use core::ops::Deref;
+use core::str::FromStr;
+use smallstr::SmallString;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Border piece or fill, a string of one or more graphemes.
+///
+/// Converts from a `char`, `&str` or `String`, and derefs to `str`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Piece(SmallString<[u8; 4]>);
+
+impl Piece {
+    /// Piece as a string slice.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Deref for Piece {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Piece {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<char> for Piece {
+    fn from(piece: char) -> Self {
+        Self(piece.into())
+    }
+}
+
+impl From<&str> for Piece {
+    fn from(piece: &str) -> Self {
+        Self(piece.into())
+    }
+}
+
+impl From<String> for Piece {
+    fn from(piece: String) -> Self {
+        Self(piece.into())
+    }
+}
+
+impl fmt::Display for Piece {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// The eight border pieces and the fill of a frame.
+///
+/// The compact form lists the pieces as a 3×3 grid read row by row,
+/// with the fill in the middle. A set with a piece other than a single
+/// grapheme is written one piece per line instead, so it parses back
+/// to the same set. Pieces holding a newline are not supported.
+///
+/// # Examples
+///
+/// ```
+/// # use txtframe::*;
+/// let border_set: BorderSet = "┏━┓┃░┃┗━┛".parse().unwrap();
+/// let text_frame = TextFrame::new().border(&border_set);
+///
+/// assert_eq!(text_frame.border_set(), border_set);
+/// assert_eq!(border_set.to_string(), "┏━┓┃░┃┗━┛");
+/// assert_eq!(
+///     BorderSet::from(FrameVar::Heavy).fill('░'),
+///     border_set
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(default = "BorderSet::new", deny_unknown_fields)
+)]
+pub struct BorderSet {
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "left_top", with = "crate::serde_impl::glyph")
+    )]
+    pub(crate) left_top_cnr: Piece,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "top_line", with = "crate::serde_impl::glyph")
+    )]
+    pub(crate) hor_top_line: Piece,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "right_top", with = "crate::serde_impl::glyph")
+    )]
+    pub(crate) right_top_cnr: Piece,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "vert_left", with = "crate::serde_impl::glyph")
+    )]
+    pub(crate) vert_left_line: Piece,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "vert_right", with = "crate::serde_impl::glyph")
+    )]
+    pub(crate) vert_right_line: Piece,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "left_btm", with = "crate::serde_impl::glyph")
+    )]
+    pub(crate) left_btm_cnr: Piece,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "btm_line", with = "crate::serde_impl::glyph")
+    )]
+    pub(crate) hor_btm_line: Piece,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "right_btm", with = "crate::serde_impl::glyph")
+    )]
+    pub(crate) right_btm_cnr: Piece,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impl::glyph"))]
+    pub(crate) fill: Piece,
+}
+
+impl Default for BorderSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BorderSet {
+    /// Construct a light border set with a space fill.
+    pub fn new() -> Self {
+        FrameVar::Light.into()
+    }
+
+    fn from_grid(grid: [char; 9]) -> Self {
+        let [left_top, top, right_top, left, fill, right, left_btm, btm, right_btm] = grid;
+        Self {
+            left_top_cnr: left_top.into(),
+            hor_top_line: top.into(),
+            right_top_cnr: right_top.into(),
+            vert_left_line: left.into(),
+            vert_right_line: right.into(),
+            left_btm_cnr: left_btm.into(),
+            hor_btm_line: btm.into(),
+            right_btm_cnr: right_btm.into(),
+            fill: fill.into(),
+        }
+    }
+
+    /// Specifies the top left corner.
+    pub fn left_top(mut self, piece: impl Into<Piece>) -> Self {
+        self.left_top_cnr = piece.into();
+        self
+    }
+
+    /// Change top left corner.
+    #[inline]
+    pub fn set_left_top(&mut self, piece: impl Into<Piece>) -> &mut Self {
+        self.left_top_cnr = piece.into();
+        self
+    }
+
+    /// Top left corner.
+    #[inline]
+    pub fn get_left_top(&self) -> &str {
+        &self.left_top_cnr
+    }
+
+    /// Specifies the top line.
+    pub fn top_line(mut self, piece: impl Into<Piece>) -> Self {
+        self.hor_top_line = piece.into();
+        self
+    }
+
+    /// Change top line.
+    #[inline]
+    pub fn set_top_line(&mut self, piece: impl Into<Piece>) -> &mut Self {
+        self.hor_top_line = piece.into();
+        self
+    }
+
+    /// Top line.
+    #[inline]
+    pub fn get_top_line(&self) -> &str {
+        &self.hor_top_line
+    }
+
+    /// Specifies the top right corner.
+    pub fn right_top(mut self, piece: impl Into<Piece>) -> Self {
+        self.right_top_cnr = piece.into();
+        self
+    }
+
+    /// Change top right corner.
+    #[inline]
+    pub fn set_right_top(&mut self, piece: impl Into<Piece>) -> &mut Self {
+        self.right_top_cnr = piece.into();
+        self
+    }
+
+    /// Top right corner.
+    #[inline]
+    pub fn get_right_top(&self) -> &str {
+        &self.right_top_cnr
+    }
+
+    /// Specifies the left vertical line.
+    pub fn vert_left(mut self, piece: impl Into<Piece>) -> Self {
+        self.vert_left_line = piece.into();
+        self
+    }
+
+    /// Change left vertical line.
+    #[inline]
+    pub fn set_vert_left(&mut self, piece: impl Into<Piece>) -> &mut Self {
+        self.vert_left_line = piece.into();
+        self
+    }
+
+    /// Left vertical line.
+    #[inline]
+    pub fn get_vert_left(&self) -> &str {
+        &self.vert_left_line
+    }
+
+    /// Specifies the right vertical line.
+    pub fn vert_right(mut self, piece: impl Into<Piece>) -> Self {
+        self.vert_right_line = piece.into();
+        self
+    }
+
+    /// Change right vertical line.
+    #[inline]
+    pub fn set_vert_right(&mut self, piece: impl Into<Piece>) -> &mut Self {
+        self.vert_right_line = piece.into();
+        self
+    }
+
+    /// Right vertical line.
+    #[inline]
+    pub fn get_vert_right(&self) -> &str {
+        &self.vert_right_line
+    }
+
+    /// Specifies the bottom left corner.
+    pub fn left_btm(mut self, piece: impl Into<Piece>) -> Self {
+        self.left_btm_cnr = piece.into();
+        self
+    }
+
+    /// Change bottom left corner.
+    #[inline]
+    pub fn set_left_btm(&mut self, piece: impl Into<Piece>) -> &mut Self {
+        self.left_btm_cnr = piece.into();
+        self
+    }
+
+    /// Bottom left corner.
+    #[inline]
+    pub fn get_left_btm(&self) -> &str {
+        &self.left_btm_cnr
+    }
+
+    /// Specifies the bottom line.
+    pub fn btm_line(mut self, piece: impl Into<Piece>) -> Self {
+        self.hor_btm_line = piece.into();
+        self
+    }
+
+    /// Change bottom line.
+    #[inline]
+    pub fn set_btm_line(&mut self, piece: impl Into<Piece>) -> &mut Self {
+        self.hor_btm_line = piece.into();
+        self
+    }
+
+    /// Bottom line.
+    #[inline]
+    pub fn get_btm_line(&self) -> &str {
+        &self.hor_btm_line
+    }
+
+    /// Specifies the bottom right corner.
+    pub fn right_btm(mut self, piece: impl Into<Piece>) -> Self {
+        self.right_btm_cnr = piece.into();
+        self
+    }
+
+    /// Change bottom right corner.
+    #[inline]
+    pub fn set_right_btm(&mut self, piece: impl Into<Piece>) -> &mut Self {
+        self.right_btm_cnr = piece.into();
+        self
+    }
+
+    /// Bottom right corner.
+    #[inline]
+    pub fn get_right_btm(&self) -> &str {
+        &self.right_btm_cnr
+    }
+
+    /// Specifies the fill character.
+    pub fn fill(mut self, fill: impl Into<Piece>) -> Self {
+        self.fill = fill.into();
+        self
+    }
+
+    /// Change fill character.
+    #[inline]
+    pub fn set_fill(&mut self, fill: impl Into<Piece>) -> &mut Self {
+        self.fill = fill.into();
+        self
+    }
+
+    /// Fill character.
+    #[inline]
+    pub fn get_fill(&self) -> &str {
+        &self.fill
+    }
+}
+
+impl From<FrameVar> for BorderSet {
+    fn from(vars: FrameVar) -> Self {
+        match vars {
+            FrameVar::Space => Self::from_grid([' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ']),
+            FrameVar::Double => Self::from_grid(['╔', '═', '╗', '║', ' ', '║', '╚', '═', '╝']),
+            FrameVar::Round => Self::from_grid(['╭', '─', '╮', '│', ' ', '│', '╰', '─', '╯']),
+            FrameVar::HorDouble => Self::from_grid(['╒', '═', '╕', '│', ' ', '│', '╘', '═', '╛']),
+            FrameVar::VertDouble => Self::from_grid(['╓', '─', '╖', '║', ' ', '║', '╙', '─', '╜']),
+            FrameVar::Heavy => Self::from_grid(['┏', '━', '┓', '┃', ' ', '┃', '┗', '━', '┛']),
+            FrameVar::Light => Self::from_grid(['┌', '─', '┐', '│', ' ', '│', '└', '─', '┘']),
+            FrameVar::VertHeavy => Self::from_grid(['┎', '─', '┒', '┃', ' ', '┃', '┖', '─', '┚']),
+            FrameVar::HorHeavy => Self::from_grid(['┍', '━', '┑', '│', ' ', '│', '┕', '━', '┙']),
+            FrameVar::Dashed => Self::from_grid(['┌', '┄', '┐', '┆', ' ', '┆', '└', '┄', '┘']),
+            FrameVar::HeavyDashed => Self::from_grid(['┏', '┅', '┓', '┇', ' ', '┇', '┗', '┅', '┛']),
+            FrameVar::DoubleDashed => {
+                Self::from_grid(['┌', '╌', '┐', '╎', ' ', '╎', '└', '╌', '┘'])
+            }
+            FrameVar::Ascii => Self::from_grid(['+', '-', '+', '|', ' ', '|', '+', '-', '+']),
+            FrameVar::AsciiDouble => Self::from_grid(['+', '=', '+', '|', ' ', '|', '+', '=', '+']),
+            FrameVar::Hash => Self::from_grid(['#', '#', '#', '#', ' ', '#', '#', '#', '#']),
+            FrameVar::Star => Self::from_grid(['*', '*', '*', '*', ' ', '*', '*', '*', '*']),
+            FrameVar::Block => Self::from_grid(['█', '█', '█', '█', ' ', '█', '█', '█', '█']),
+            FrameVar::Quadrant => Self::from_grid(['▛', '▀', '▜', '▌', ' ', '▐', '▙', '▄', '▟']),
+            FrameVar::HalfBlock => Self::from_grid(['▄', '▄', '▄', '█', ' ', '█', '▀', '▀', '▀']),
+        }
+    }
+}
+
+impl FromStr for BorderSet {
+    type Err = ParseBorderSetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let pieces = if s.contains('\n') {
+            s.split('\n').collect::<Vec<_>>()
+        } else {
+            s.graphemes(true).collect::<Vec<_>>()
+        };
+        let [left_top, top, right_top, left, fill, right, left_btm, btm, right_btm] = pieces[..]
+        else {
+            return Err(ParseBorderSetError(pieces.len()));
+        };
+
+        Ok(Self {
+            left_top_cnr: left_top.into(),
+            hor_top_line: top.into(),
+            right_top_cnr: right_top.into(),
+            vert_left_line: left.into(),
+            vert_right_line: right.into(),
+            left_btm_cnr: left_btm.into(),
+            hor_btm_line: btm.into(),
+            right_btm_cnr: right_btm.into(),
+            fill: fill.into(),
+        })
+    }
+}
+
+impl fmt::Display for BorderSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pieces = [
+            &self.left_top_cnr,
+            &self.hor_top_line,
+            &self.right_top_cnr,
+            &self.vert_left_line,
+            &self.fill,
+            &self.vert_right_line,
+            &self.left_btm_cnr,
+            &self.hor_btm_line,
+            &self.right_btm_cnr,
+        ];
+        let compact = pieces
+            .iter()
+            .all(|piece| piece.graphemes(true).count() == 1 && !piece.contains('\n'));
+        if compact {
+            return pieces.iter().try_for_each(|piece| f.write_str(piece));
+        }
+
+        for (idx, piece) in pieces.iter().enumerate() {
+            if idx > 0 {
+                f.write_str("\n")?;
+            }
+            f.write_str(piece)?;
+        }
+        Ok(())
+    }
+}
+
+/// Error parsing a [`BorderSet`] from its compact form.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseBorderSetError(usize);
+
+impl fmt::Display for ParseBorderSetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected 9 border pieces, found {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseBorderSetError {}
@@ -1,6 +1,8 @@
 /// Eight standard colors and default.
 #[allow(missing_docs)]
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Color {
     #[default]
     Default,
@@ -30,4 +32,71 @@ impl Color {
             Color::Default => "\x1b[0m",
         }
     }
+
+    pub(crate) fn into_bg_str(self) -> &'static str {
+        match self {
+            Color::Black => "\x1b[40m",
+            Color::Red => "\x1b[41m",
+            Color::Green => "\x1b[42m",
+            Color::Yellow => "\x1b[43m",
+            Color::Blue => "\x1b[44m",
+            Color::Magenta => "\x1b[45m",
+            Color::Cyan => "\x1b[46m",
+            Color::White => "\x1b[47m",
+            Color::Default => "\x1b[49m",
+        }
+    }
+
+    /// RGB value of the color in the xterm palette, `None` for the terminal default.
+    pub fn rgb(self) -> Option<(u8, u8, u8)> {
+        match self {
+            Color::Black => Some((0, 0, 0)),
+            Color::Red => Some((205, 0, 0)),
+            Color::Green => Some((0, 205, 0)),
+            Color::Yellow => Some((205, 205, 0)),
+            Color::Blue => Some((0, 0, 238)),
+            Color::Magenta => Some((205, 0, 205)),
+            Color::Cyan => Some((0, 205, 205)),
+            Color::White => Some((229, 229, 229)),
+            Color::Default => None,
+        }
+    }
+
+    /// WCAG contrast ratio between two colors, from 1.0 to 21.0.
+    pub fn contrast_ratio(self, other: Color) -> Option<f64> {
+        let lum_a = relative_luminance(self.rgb()?);
+        let lum_b = relative_luminance(other.rgb()?);
+        let (light, dark) = if lum_a > lum_b {
+            (lum_a, lum_b)
+        } else {
+            (lum_b, lum_a)
+        };
+
+        Some((light + 0.05) / (dark + 0.05))
+    }
+
+    /// Black or white, whichever is more readable on this background.
+    pub fn readable(self) -> Color {
+        match (
+            self.contrast_ratio(Color::Black),
+            self.contrast_ratio(Color::White),
+        ) {
+            (Some(black), Some(white)) if black > white => Color::Black,
+            (Some(_), Some(_)) => Color::White,
+            _ => Color::Default,
+        }
+    }
+}
+
+fn relative_luminance((red, green, blue): (u8, u8, u8)) -> f64 {
+    let channel = |value: u8| {
+        let value = f64::from(value) / 255.0;
+        if value <= 0.03928 {
+            value / 12.92
+        } else {
+            ((value + 0.055) / 1.055).powf(2.4)
+        }
+    };
+
+    0.2126 * channel(red) + 0.7152 * channel(green) + 0.0722 * channel(blue)
 }
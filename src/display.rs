@@ -0,0 +1,44 @@
+use crate::{Algn, TextFrame};
+use core::fmt;
+
+/// Text in a frame, shown with [`Display`](fmt::Display).
+///
+/// The width and alignment of the format spec override those of the frame,
+/// so `{:^60}` centers the text in a frame at least 60 columns wide.
+///
+/// # Examples
+///
+/// ```
+/// # use txtframe::*;
+/// let text_frame = TextFrame::new();
+/// # #[cfg(not(feature = "color"))]
+/// assert_eq!(
+///     format!("{:>6}", text_frame.display("ab")),
+///     "┌────┐\n│  ab│\n└────┘"
+/// );
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct FrameDisplay<'a> {
+    frame: &'a TextFrame,
+    text: &'a str,
+}
+
+impl<'a> FrameDisplay<'a> {
+    pub(crate) fn new(frame: &'a TextFrame, text: &'a str) -> Self {
+        Self { frame, text }
+    }
+}
+
+impl fmt::Display for FrameDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let algn = f.align().map(|align| match align {
+            fmt::Alignment::Left => Algn::Left,
+            fmt::Alignment::Center => Algn::Centr,
+            fmt::Alignment::Right => Algn::Right,
+        });
+
+        self.frame
+            .frame_iter_sized(self.text, f.width(), algn)
+            .try_for_each(|piece| f.write_str(piece))
+    }
+}
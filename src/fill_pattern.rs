@@ -0,0 +1,48 @@
+/// How the fill graphemes are laid out.
+///
+/// Except for [`FillPattern::Plain`], every fill grapheme takes a cell as wide
+/// as the widest one, and cells are anchored to the frame coordinates, so the
+/// pattern lines up across rows, around the text and between rings.
+///
+/// # Examples
+///
+/// ```
+/// # use txtframe::*;
+/// let text_frame = TextFrame::new()
+///     .fill("░▒")
+///     .fill_pattern(FillPattern::Checker)
+///     .width(6)
+///     .height(4)
+///     .sides(Sides::NONE);
+/// # #[cfg(not(feature = "color"))]
+/// assert_eq!(
+///     text_frame.frame_iter("").collect::<String>(),
+///     "░▒░▒░▒\n▒░▒░▒░\n░▒░▒░▒\n▒░▒░▒░"
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum FillPattern {
+    /// The whole fill repeated from the start of each run.
+    #[default]
+    Plain,
+    /// Graphemes cycled along each row.
+    Repeat,
+    /// Shifted by one grapheme every row, a checkerboard for two graphemes.
+    Checker,
+    /// Shifted back by one grapheme every row, diagonal stripes running down to the right.
+    Stripes,
+}
+
+impl FillPattern {
+    /// Grapheme shift per row, `None` for the plain fill.
+    pub(crate) fn shift(self) -> Option<isize> {
+        match self {
+            FillPattern::Plain => None,
+            FillPattern::Repeat => Some(0),
+            FillPattern::Checker => Some(1),
+            FillPattern::Stripes => Some(-1),
+        }
+    }
+}
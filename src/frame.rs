@@ -0,0 +1,48 @@
+use crate::{FramedText, TextFrame};
+use core::fmt;
+
+/// Frame any value shown with [`Display`](fmt::Display).
+///
+/// # Examples
+///
+/// ```
+/// # use txtframe::*;
+/// let err = std::fmt::Error;
+/// let framed = err.framed(&TextFrame::new().width(20));
+/// assert_eq!(framed.get_text(), err.to_string());
+/// ```
+pub trait Frame {
+    /// Render the value in the frame.
+    fn framed(&self, frame: &TextFrame) -> FramedText;
+}
+
+impl<T: fmt::Display + ?Sized> Frame for T {
+    fn framed(&self, frame: &TextFrame) -> FramedText {
+        frame.framed(self.to_string())
+    }
+}
+
+/// Format text into a copy of the frame, returning a [`FramedText`].
+///
+/// Options between the frame and the format string call the frame builder
+/// of the same name, such as `width = 40` or `algn = Algn::Centr`.
+///
+/// # Examples
+///
+/// ```
+/// # use txtframe::*;
+/// let style = TextFrame::new();
+/// let framed = frame!(style, width = 8, algn = Algn::Right, "{}-{}", 1, 2);
+/// assert_eq!(framed.plain(), "┌──────┐\n│   1-2│\n└──────┘");
+///
+/// let framed = frame!(&style, "{} to {}", "a", "b");
+/// assert_eq!(framed.to_string(), style.frame_iter("a to b").collect::<String>());
+/// ```
+#[macro_export]
+macro_rules! frame {
+    ($frame:expr, $($opt:ident = $val:expr,)* $fmt:literal $($args:tt)*) => {
+        $crate::TextFrame::clone(&$frame)
+            $(.$opt($val))*
+            .framed(::std::format!($fmt $($args)*))
+    };
+}
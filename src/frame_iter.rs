@@ -0,0 +1,102 @@
+use crate::txt_frame::Layout;
+use crate::TextFrame;
+use core::fmt;
+use core::iter::FusedIterator;
+use core::str::Lines;
+
+/// Iterator over the pieces of a frame, created by [`TextFrame::frame_iter`].
+///
+/// Rows are rendered one at a time as the iterator advances.
+///
+/// The size hint is a lower bound, not an exact count: the pieces of a row
+/// depend on its colors, patterns and highlights, so counting them means
+/// rendering the frame twice. To pre-size a buffer, use [`TextFrame::byte_len`].
+///
+/// # Examples
+///
+/// ```
+/// # use txtframe::*;
+/// let text_frame = TextFrame::new();
+/// let frame_iter: FrameIter<'_> = text_frame.frame_iter("ab");
+///
+/// assert!(frame_iter.size_hint().0 <= frame_iter.clone().count());
+/// ```
+#[derive(Clone)]
+pub struct FrameIter<'a> {
+    frame: &'a TextFrame,
+    layout: Layout,
+    lines: Lines<'a>,
+    idx: usize,
+    row: Vec<&'a str>,
+    pos: usize,
+}
+
+impl<'a> FrameIter<'a> {
+    pub(crate) fn new(frame: &'a TextFrame, text: &'a str, layout: Layout) -> Self {
+        Self {
+            frame,
+            layout,
+            lines: text.lines(),
+            idx: 0,
+            row: Vec::new(),
+            pos: 0,
+        }
+    }
+}
+
+/// Renders the row at the index into `pieces`, `None` after the last row.
+fn push_row_at<'a>(
+    frame: &'a TextFrame,
+    layout: &Layout,
+    idx: usize,
+    lines: &mut Lines<'a>,
+    pieces: &mut Vec<&'a str>,
+) -> Option<()> {
+    let row = frame.row_at(layout, idx, lines)?;
+    if idx > 0 {
+        pieces.push("\n");
+    }
+    frame.push_line(layout, idx, row, pieces);
+    Some(())
+}
+
+impl<'a> Iterator for FrameIter<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pos == self.row.len() {
+            self.row.clear();
+            self.pos = 0;
+            push_row_at(
+                self.frame,
+                &self.layout,
+                self.idx,
+                &mut self.lines,
+                &mut self.row,
+            )?;
+            self.idx += 1;
+        }
+
+        let piece = self.row[self.pos];
+        self.pos += 1;
+        Some(piece)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every row after the first starts with a newline.
+        let rows = self.frame.row_count(&self.layout);
+        let newlines = rows.saturating_sub(self.idx.max(1));
+        (self.row.len() - self.pos + newlines, None)
+    }
+}
+
+impl FusedIterator for FrameIter<'_> {}
+
+impl fmt::Debug for FrameIter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FrameIter")
+            .field("row", &self.idx)
+            .field("pieces", &&self.row[self.pos..])
+            .finish_non_exhaustive()
+    }
+}
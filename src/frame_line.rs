@@ -0,0 +1,82 @@
+use core::fmt;
+#[cfg(feature = "esc")]
+#[cfg(feature = "color")]
+use strip_ansi_escapes::strip;
+
+/// A row of a frame.
+///
+/// # Examples
+///
+/// ```
+/// # use txtframe::*;
+/// let text_frame = TextFrame::new();
+/// let lines = text_frame.frame_lines("ab").collect::<Vec<_>>();
+///
+/// assert_eq!(lines.len(), 3);
+/// assert_eq!(lines[1].plain(), "│ab│");
+/// assert_eq!(lines[1].width(), 4);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FrameLine {
+    styled: String,
+    plain: String,
+    width: usize,
+}
+
+impl FrameLine {
+    pub(crate) fn new(pieces: &[&str], str_width: fn(&str) -> usize) -> Self {
+        let styled = pieces.concat();
+        let plain = pieces
+            .iter()
+            .filter(|piece| !is_esc(piece))
+            .copied()
+            .collect::<String>();
+        let width = str_width(&plain);
+
+        Self {
+            styled,
+            plain,
+            width,
+        }
+    }
+
+    /// Removes the escape codes left in the plain row.
+    #[cfg(feature = "esc")]
+    #[cfg(feature = "color")]
+    pub(crate) fn strip_plain(mut self) -> Self {
+        self.plain = String::from_utf8(strip(&self.plain).unwrap()).unwrap();
+        self
+    }
+
+    /// Row with escape codes.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.styled
+    }
+
+    /// Row without escape codes.
+    #[inline]
+    pub fn plain(&self) -> &str {
+        &self.plain
+    }
+
+    /// Display width of the row.
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+}
+
+impl fmt::Display for FrameLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.styled)
+    }
+}
+
+/// Piece made of a single escape code.
+pub(crate) fn is_esc(piece: &str) -> bool {
+    piece
+        .strip_prefix("\x1b[")
+        .and_then(|code| code.strip_suffix('m'))
+        .is_some_and(|code| code.bytes().all(|byte| byte.is_ascii_digit()))
+}
@@ -0,0 +1,33 @@
+/// Size of a frame around a text, in columns and rows.
+///
+/// # Examples
+///
+/// ```
+/// # use txtframe::*;
+/// let size = TextFrame::new().expand(1).measure("ab\ncd");
+///
+/// assert_eq!((size.width, size.height), (6, 6));
+/// assert_eq!(size.inner, FrameRect { x: 1, y: 1, width: 4, height: 4 });
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FrameSize {
+    /// Columns of the widest row, shadow included.
+    pub width: usize,
+    /// Rows of the whole frame, shadow included.
+    pub height: usize,
+    /// Area inside the innermost border, holding the text and fill.
+    pub inner: FrameRect,
+}
+
+/// A rectangle of a frame, from its top left cell.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FrameRect {
+    /// Column of the left edge.
+    pub x: usize,
+    /// Row of the top edge.
+    pub y: usize,
+    /// Columns.
+    pub width: usize,
+    /// Rows.
+    pub height: usize,
+}
@@ -1,14 +1,145 @@
+use core::fmt;
+use core::str::FromStr;
+
 /// Sets a predetermined frame.
-#[allow(missing_docs)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum FrameVar {
+    /// Blank border of spaces.
     Space,
+    /// Double lines, `╔═╗` and `║`.
     Double,
+    /// Light lines with round corners, `╭─╮` and `│`.
     Round,
+    /// Double top and bottom lines, light sides, `╒═╕` and `│`.
     HorDouble,
+    /// Light top and bottom lines, double sides, `╓─╖` and `║`.
     VertDouble,
+    /// Heavy lines, `┏━┓` and `┃`.
     Heavy,
+    /// Light lines, `┌─┐` and `│`.
     Light,
+    /// Light top and bottom lines, heavy sides, `┎─┒` and `┃`.
     VertHeavy,
+    /// Heavy top and bottom lines, light sides, `┍━┑` and `│`.
     HorHeavy,
+    /// Light dashed lines, `┌┄┐` and `┆`.
+    Dashed,
+    /// Heavy dashed lines, `┏┅┓` and `┇`.
+    HeavyDashed,
+    /// Light double-dashed lines, `┌╌┐` and `╎`.
+    DoubleDashed,
+    /// ASCII lines, `+-+` and `|`.
+    Ascii,
+    /// ASCII lines with double top and bottom, `+=+` and `|`.
+    AsciiDouble,
+    /// Hash signs, `###` and `#`.
+    Hash,
+    /// Asterisks, `***` and `*`.
+    Star,
+    /// Full blocks, `███` and `█`.
+    Block,
+    /// Quadrant blocks hugging the text, `▛▀▜` and `▌`, `▐`.
+    Quadrant,
+    /// Half-height top and bottom lines, best with a background equal to the frame color.
+    HalfBlock,
 }
+
+impl FrameVar {
+    /// Every predetermined frame.
+    pub const ALL: [FrameVar; 19] = [
+        FrameVar::Space,
+        FrameVar::Double,
+        FrameVar::Round,
+        FrameVar::HorDouble,
+        FrameVar::VertDouble,
+        FrameVar::Heavy,
+        FrameVar::Light,
+        FrameVar::VertHeavy,
+        FrameVar::HorHeavy,
+        FrameVar::Dashed,
+        FrameVar::HeavyDashed,
+        FrameVar::DoubleDashed,
+        FrameVar::Ascii,
+        FrameVar::AsciiDouble,
+        FrameVar::Hash,
+        FrameVar::Star,
+        FrameVar::Block,
+        FrameVar::Quadrant,
+        FrameVar::HalfBlock,
+    ];
+
+    /// Human readable name.
+    pub fn name(self) -> &'static str {
+        match self {
+            FrameVar::Space => "Space",
+            FrameVar::Double => "Double",
+            FrameVar::Round => "Round",
+            FrameVar::HorDouble => "Horizontal double",
+            FrameVar::VertDouble => "Vertical double",
+            FrameVar::Heavy => "Heavy",
+            FrameVar::Light => "Light",
+            FrameVar::VertHeavy => "Vertical heavy",
+            FrameVar::HorHeavy => "Horizontal heavy",
+            FrameVar::Dashed => "Dashed",
+            FrameVar::HeavyDashed => "Heavy dashed",
+            FrameVar::DoubleDashed => "Double dashed",
+            FrameVar::Ascii => "ASCII",
+            FrameVar::AsciiDouble => "ASCII double",
+            FrameVar::Hash => "Hash",
+            FrameVar::Star => "Star",
+            FrameVar::Block => "Block",
+            FrameVar::Quadrant => "Quadrant",
+            FrameVar::HalfBlock => "Half block",
+        }
+    }
+
+    /// Machine readable identifier, accepted by [`str::parse`].
+    pub fn id(self) -> &'static str {
+        match self {
+            FrameVar::Space => "space",
+            FrameVar::Double => "double",
+            FrameVar::Round => "round",
+            FrameVar::HorDouble => "hor_double",
+            FrameVar::VertDouble => "vert_double",
+            FrameVar::Heavy => "heavy",
+            FrameVar::Light => "light",
+            FrameVar::VertHeavy => "vert_heavy",
+            FrameVar::HorHeavy => "hor_heavy",
+            FrameVar::Dashed => "dashed",
+            FrameVar::HeavyDashed => "heavy_dashed",
+            FrameVar::DoubleDashed => "double_dashed",
+            FrameVar::Ascii => "ascii",
+            FrameVar::AsciiDouble => "ascii_double",
+            FrameVar::Hash => "hash",
+            FrameVar::Star => "star",
+            FrameVar::Block => "block",
+            FrameVar::Quadrant => "quadrant",
+            FrameVar::HalfBlock => "half_block",
+        }
+    }
+}
+
+impl FromStr for FrameVar {
+    type Err = ParseFrameVarError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        FrameVar::ALL
+            .into_iter()
+            .find(|vars| vars.id() == s)
+            .ok_or(ParseFrameVarError)
+    }
+}
+
+/// Error parsing a [`FrameVar`] from its identifier.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseFrameVarError;
+
+impl fmt::Display for ParseFrameVarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("unknown frame variant")
+    }
+}
+
+impl std::error::Error for ParseFrameVarError {}
@@ -0,0 +1,218 @@
+use crate::stream::{FrameStream, Overflow};
+use crate::TextFrame;
+use std::collections::VecDeque;
+use std::io::{self, Write};
+
+/// A writer framing the text written to it, created by [`TextFrame::writer`]
+/// and [`TextFrame::line_writer`].
+///
+/// Every row of the frame is followed by a newline. The frame is completed
+/// on drop, ignoring errors; use [`FrameWriter::finish`] to see them.
+///
+/// Rows the underlying writer fails to take are kept and written first on
+/// the next call, so an error never loses or repeats output. Once `write`
+/// accepts the text it returns `Ok`; a failed row shows up as an error from
+/// the next `write`, `flush` or `finish`.
+///
+/// # Examples
+///
+/// ```
+/// # use txtframe::*;
+/// use std::io::Write;
+///
+/// let text_frame = TextFrame::new();
+/// let mut writer = text_frame.line_writer(6, Overflow::Wrap, Vec::new());
+/// writeln!(writer, "ab").unwrap();
+/// # #[cfg(not(feature = "color"))]
+/// assert_eq!(writer.get_ref(), "┌────┐\n│ab  │\n".as_bytes());
+///
+/// let out = writer.finish().unwrap();
+/// # #[cfg(not(feature = "color"))]
+/// assert_eq!(out, "┌────┐\n│ab  │\n└────┘\n".as_bytes());
+/// ```
+#[derive(Debug)]
+pub struct FrameWriter<W: Write> {
+    out: Option<W>,
+    mode: Mode,
+    /// Framed output not yet taken by the writer.
+    pending: Vec<u8>,
+    /// Bytes of `pending` already written.
+    written: usize,
+}
+
+#[derive(Debug)]
+enum Mode {
+    /// Frames the whole text on flush.
+    Buffered {
+        frame: Box<TextFrame>,
+        text: Vec<u8>,
+    },
+    /// Frames each line as soon as it is complete.
+    Lines {
+        stream: Box<FrameStream<PushLines>>,
+        partial: Vec<u8>,
+    },
+}
+
+/// Lines written so far and not yet framed.
+#[derive(Debug, Default)]
+struct PushLines(VecDeque<String>);
+
+impl Iterator for PushLines {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front().map(Ok)
+    }
+}
+
+impl<W: Write> FrameWriter<W> {
+    pub(crate) fn buffered(frame: TextFrame, out: W) -> Self {
+        Self {
+            out: Some(out),
+            mode: Mode::Buffered {
+                frame: Box::new(frame),
+                text: Vec::new(),
+            },
+            pending: Vec::new(),
+            written: 0,
+        }
+    }
+
+    pub(crate) fn lines(frame: TextFrame, width: usize, overflow: Overflow, out: W) -> Self {
+        let stream = frame.stream_iter(width, overflow, PushLines::default());
+        Self {
+            out: Some(out),
+            mode: Mode::Lines {
+                stream: Box::new(stream),
+                partial: Vec::new(),
+            },
+            pending: Vec::new(),
+            written: 0,
+        }
+    }
+
+    /// Completes the frame and returns the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.close()?;
+        Ok(self.out.take().expect("writer taken only here"))
+    }
+
+    /// Underlying writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        self.out.as_ref().expect("writer taken only in finish")
+    }
+
+    /// Underlying writer, writing to it directly may corrupt the frame.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        self.out.as_mut().expect("writer taken only in finish")
+    }
+
+    /// Writes the pending frame, or the rest of the streamed frame.
+    fn close(&mut self) -> io::Result<()> {
+        if self.out.is_none() {
+            return Ok(());
+        }
+
+        match &mut self.mode {
+            Mode::Buffered { frame, text } => frame_buffered(&mut self.pending, frame, text),
+            Mode::Lines { stream, partial } => {
+                if !partial.is_empty() {
+                    let line = String::from_utf8_lossy(partial).into_owned();
+                    stream.source_mut().0.push_back(line);
+                    partial.clear();
+                }
+                frame_rows(&mut self.pending, stream, true);
+            }
+        }
+        self.write_pending()?;
+        self.get_mut().flush()
+    }
+
+    /// Writes the framed output the writer has not taken yet.
+    ///
+    /// On error the unwritten bytes are kept for the next call.
+    fn write_pending(&mut self) -> io::Result<()> {
+        let out = self.out.as_mut().expect("writer taken only in finish");
+        while self.written < self.pending.len() {
+            match out.write(&self.pending[self.written..]) {
+                Ok(0) => return Err(io::ErrorKind::WriteZero.into()),
+                Ok(n) => self.written += n,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        }
+        self.pending.clear();
+        self.written = 0;
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for FrameWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // Nothing is accepted until the earlier rows are out.
+        self.write_pending()?;
+
+        match &mut self.mode {
+            Mode::Buffered { text, .. } => text.extend_from_slice(buf),
+            Mode::Lines { stream, partial } => {
+                partial.extend_from_slice(buf);
+                if let Some(last) = partial.iter().rposition(|byte| *byte == b'\n') {
+                    let lines = partial.drain(..=last).collect::<Vec<_>>();
+                    let text = String::from_utf8_lossy(&lines[..last]);
+                    stream.source_mut().0.extend(
+                        text.split('\n')
+                            .map(|line| line.trim_end_matches('\r').into()),
+                    );
+                }
+                frame_rows(&mut self.pending, stream, false);
+                // The text is taken; rows the writer fails on wait for the next call.
+                let _ = self.write_pending();
+            }
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if let Mode::Buffered { frame, text } = &mut self.mode {
+            frame_buffered(&mut self.pending, frame, text);
+        }
+        self.write_pending()?;
+        self.get_mut().flush()
+    }
+}
+
+impl<W: Write> Drop for FrameWriter<W> {
+    fn drop(&mut self) {
+        let _ = self.close();
+    }
+}
+
+/// Frames the buffered text, if any, into `pending` and clears the buffer.
+fn frame_buffered(pending: &mut Vec<u8>, frame: &TextFrame, text: &mut Vec<u8>) {
+    if text.is_empty() {
+        return;
+    }
+
+    let framed = String::from_utf8_lossy(text);
+    let framed = framed.strip_suffix('\n').unwrap_or(&framed);
+    frame
+        .write_to(framed, &mut *pending)
+        .expect("writing to a Vec never fails");
+    pending.push(b'\n');
+    text.clear();
+}
+
+/// Frames the rows ready so far into `pending`, or all remaining rows once `closed`.
+fn frame_rows(pending: &mut Vec<u8>, stream: &mut FrameStream<PushLines>, closed: bool) {
+    while closed || stream.lines_needed() <= stream.source_mut().0.len() {
+        let Some(row) = stream.next() else {
+            break;
+        };
+        let row = row.expect("pushed lines never fail");
+        pending.extend_from_slice(row.as_bytes());
+        pending.push(b'\n');
+    }
+}
@@ -0,0 +1,132 @@
+use crate::frame_line::is_esc;
+use crate::{FrameSize, TextFrame};
+use core::fmt;
+
+/// Text rendered in a frame, owning both.
+///
+/// Keeps the frame size and the rows, with and without the escape codes of the frame.
+/// Escape codes in the text are kept in both, as with [`FrameLine::plain`](crate::FrameLine::plain).
+/// Changing the width renders again into the same buffers.
+///
+/// # Examples
+///
+/// ```
+/// # use txtframe::*;
+/// let mut framed = TextFrame::new().framed("ab");
+/// assert_eq!(framed.plain(), "┌──┐\n│ab│\n└──┘");
+///
+/// framed.set_width(6);
+/// assert_eq!(framed.plain(), "┌────┐\n│ab  │\n└────┘");
+/// assert_eq!(framed.plain_rows().nth(1), Some("│ab  │"));
+/// assert_eq!((framed.size().width, framed.size().height), (6, 3));
+/// assert_eq!(framed.to_string(), framed.as_str());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FramedText {
+    frame: TextFrame,
+    text: String,
+    styled: String,
+    plain: String,
+    size: FrameSize,
+}
+
+impl FramedText {
+    pub(crate) fn new(frame: TextFrame, text: String) -> Self {
+        let mut framed = Self {
+            frame,
+            text,
+            styled: String::new(),
+            plain: String::new(),
+            size: FrameSize::default(),
+        };
+        framed.render();
+        framed
+    }
+
+    fn render(&mut self) {
+        let Self {
+            frame,
+            text,
+            styled,
+            plain,
+            size,
+        } = self;
+
+        styled.clear();
+        plain.clear();
+        for piece in frame.frame_iter(text) {
+            styled.push_str(piece);
+            if !is_esc(piece) {
+                plain.push_str(piece);
+            }
+        }
+        *size = frame.measure(text);
+    }
+
+    /// Specifies the frame width and renders again.
+    pub fn width(mut self, width: usize) -> Self {
+        self.set_width(width);
+        self
+    }
+
+    /// Change frame width and render again.
+    #[inline]
+    pub fn set_width(&mut self, width: usize) -> &mut Self {
+        if self.frame.get_width() != width {
+            self.frame.set_width(width);
+            self.render();
+        }
+        self
+    }
+
+    /// Frame with escape codes.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.styled
+    }
+
+    /// Frame without the escape codes of the frame.
+    #[inline]
+    pub fn plain(&self) -> &str {
+        &self.plain
+    }
+
+    /// Rows of the frame with escape codes.
+    pub fn rows(&self) -> impl Iterator<Item = &str> + '_ {
+        self.styled.split('\n')
+    }
+
+    /// Rows of the frame without the escape codes of the frame.
+    pub fn plain_rows(&self) -> impl Iterator<Item = &str> + '_ {
+        self.plain.split('\n')
+    }
+
+    /// Size of the frame and the area inside the innermost border.
+    #[inline]
+    pub fn size(&self) -> FrameSize {
+        self.size
+    }
+
+    /// Framed text.
+    #[inline]
+    pub fn get_text(&self) -> &str {
+        &self.text
+    }
+
+    /// Frame settings.
+    #[inline]
+    pub fn get_frame(&self) -> &TextFrame {
+        &self.frame
+    }
+
+    /// Frame with escape codes, consuming the framed text.
+    pub fn into_string(self) -> String {
+        self.styled
+    }
+}
+
+impl fmt::Display for FramedText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.styled)
+    }
+}
@@ -0,0 +1,119 @@
+use crate::piece_sink::PieceSink;
+use crate::Color;
+use core::iter;
+
+/// A search term highlighted with its own color.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub(crate) struct Highlight {
+    pub(crate) term: String,
+    pub(crate) color: Color,
+}
+
+/// Appends the pieces of a line to `pieces`, coloring every occurrence of the terms.
+///
+/// After each occurrence the `restore` escape codes switch back to the text style,
+/// followed by the escape codes of the line before it. Escape codes in the line
+/// are never matched. Only escape codes are inserted, so the visible width of
+/// the line is unchanged.
+pub(crate) fn highlight_line<'a>(
+    line: &'a str,
+    highlights: &'a [Highlight],
+    ignore_case: bool,
+    restore: impl Iterator<Item = &'static str> + Clone,
+    pieces: &mut impl PieceSink<'a>,
+) {
+    let mut pushed = false;
+    let mut start = 0;
+    let mut pos = 0;
+
+    while pos < line.len() {
+        if let Some(len) = esc_len(&line[pos..]) {
+            pos += len;
+            continue;
+        }
+
+        let found = highlights
+            .iter()
+            .filter_map(|hl| match_at(&line[pos..], &hl.term, ignore_case).map(|len| (len, hl)))
+            .max_by_key(|(len, _)| *len);
+
+        match found {
+            Some((len, hl)) => {
+                if start < pos {
+                    pieces.push(&line[start..pos]);
+                }
+                pieces.push(hl.color.into_fg_str());
+                pieces.push(&line[pos..pos + len]);
+                for esc in restore.clone() {
+                    pieces.push(esc);
+                }
+                for esc in escapes(&line[..pos]) {
+                    pieces.push(esc);
+                }
+                pushed = true;
+                pos += len;
+                start = pos;
+            }
+            None => pos += line[pos..].chars().next().map_or(1, char::len_utf8),
+        }
+    }
+
+    if start < line.len() || !pushed {
+        pieces.push(&line[start..]);
+    }
+}
+
+/// Byte length of `term` matched at the start of `text`.
+fn match_at(text: &str, term: &str, ignore_case: bool) -> Option<usize> {
+    if term.is_empty() {
+        return None;
+    }
+
+    let mut text_chars = text.char_indices();
+    for term_ch in term.chars() {
+        let (_, ch) = text_chars.next()?;
+        let equal = if ignore_case {
+            ch.to_lowercase().eq(term_ch.to_lowercase())
+        } else {
+            ch == term_ch
+        };
+
+        if !equal {
+            return None;
+        }
+    }
+
+    Some(text_chars.next().map_or(text.len(), |(idx, _)| idx))
+}
+
+/// Byte length of the escape code at the start of `text`.
+fn esc_len(text: &str) -> Option<usize> {
+    let rest = text.strip_prefix('\x1b')?;
+    match rest.strip_prefix('[') {
+        Some(csi) => csi
+            .bytes()
+            .position(|byte| (0x40..=0x7e).contains(&byte))
+            .map(|end| end + 3),
+        None => rest.chars().next().map(|ch| 1 + ch.len_utf8()),
+    }
+}
+
+/// Escape codes of the text, in order.
+fn escapes(text: &str) -> impl Iterator<Item = &str> {
+    let mut pos = 0;
+    iter::from_fn(move || {
+        while let Some(idx) = text[pos..].find('\x1b') {
+            let start = pos + idx;
+            match esc_len(&text[start..]) {
+                Some(len) => {
+                    pos = start + len;
+                    return Some(&text[start..pos]);
+                }
+                None => pos = start + 1,
+            }
+        }
+        None
+    })
+}
@@ -44,14 +44,61 @@
 
 mod algn;
 #[cfg(feature = "color")]
+mod attr;
+mod border_set;
+#[cfg(feature = "color")]
 mod color;
+mod display;
+mod fill_pattern;
+mod frame;
+mod frame_iter;
+mod frame_line;
+mod frame_size;
 mod frame_var;
+mod frame_writer;
+mod framed_text;
+#[cfg(feature = "color")]
+mod highlight;
+mod line_pattern;
+mod line_style;
+mod pattern;
+mod piece_sink;
+mod prepared_frame;
+mod ring;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod shadow;
+mod sides;
+mod stream;
+#[cfg(feature = "color")]
+mod theme;
 mod txt_frame;
 
 pub use crate::algn::Algn;
 #[cfg(feature = "color")]
 #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+pub use crate::attr::Attr;
+pub use crate::border_set::{BorderSet, ParseBorderSetError, Piece};
+#[cfg(feature = "color")]
+#[cfg_attr(docsrs, doc(cfg(feature = "color")))]
 pub use crate::color::Color;
-pub use crate::frame_var::FrameVar;
+pub use crate::display::FrameDisplay;
+pub use crate::fill_pattern::FillPattern;
+pub use crate::frame::Frame;
+pub use crate::frame_iter::FrameIter;
+pub use crate::frame_line::FrameLine;
+pub use crate::frame_size::{FrameRect, FrameSize};
+pub use crate::frame_var::{FrameVar, ParseFrameVarError};
+pub use crate::frame_writer::FrameWriter;
+pub use crate::framed_text::FramedText;
+pub use crate::line_pattern::LinePattern;
+pub use crate::line_style::{LineStyle, LineStyles};
+pub use crate::prepared_frame::PreparedFrame;
+pub use crate::ring::Ring;
+pub use crate::shadow::{Shadow, ShadowDir};
+pub use crate::sides::Sides;
+pub use crate::stream::{FrameStream, Overflow};
+#[cfg(feature = "color")]
+#[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+pub use crate::theme::Theme;
 pub use crate::txt_frame::TextFrame;
-
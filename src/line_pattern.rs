@@ -0,0 +1,33 @@
+/// How the border lines repeat their pieces along each edge.
+///
+/// # Examples
+///
+/// ```
+/// # use txtframe::*;
+/// let text_frame = TextFrame::new()
+///     .top_line("<>")
+///     .vert_left("<=")
+///     .vert_right("=>");
+/// # #[cfg(not(feature = "color"))]
+/// assert_eq!(
+///     text_frame.frame_iter("abcdef").collect::<String>(),
+///     "┌<><><><>┐\n<=abcdef=>\n└────────┘"
+/// );
+///
+/// let text_frame = text_frame.line_pattern(LinePattern::Mirror);
+/// # #[cfg(not(feature = "color"))]
+/// assert_eq!(
+///     text_frame.frame_iter("abcdef").collect::<String>(),
+///     "┌<><<><┐\n<abcdef=\n└──────┘"
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum LinePattern {
+    /// The whole piece repeated, so a side of `"<="` is two columns wide.
+    #[default]
+    Plain,
+    /// Graphemes cycled one at a time, the second half mirroring the first.
+    Mirror,
+}
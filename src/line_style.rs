@@ -0,0 +1,179 @@
+use crate::BorderSet;
+
+/// Style of the line of one frame side.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum LineStyle {
+    /// Light line, `─` and `│`.
+    #[default]
+    Light,
+    /// Heavy line, `━` and `┃`.
+    Heavy,
+    /// Double line, `═` and `║`.
+    Double,
+    /// Light dashed line, `┄` and `┆`, joined by light corners.
+    Dashed,
+    /// Hidden side.
+    None,
+}
+
+/// Line styles of the four frame sides.
+///
+/// The corners are picked from the styles of the two sides they join.
+/// Heavy and double lines have no common corner, so where they meet the
+/// corner keeps the double line and draws the heavy side as light,
+/// e.g. `╓` for a heavy top and a double left side.
+///
+/// # Examples
+///
+/// ```
+/// # use txtframe::*;
+/// let line_styles = LineStyles::all(LineStyle::Light).top(LineStyle::Heavy);
+/// let text_frame = TextFrame::new().line_styles(line_styles);
+///
+/// assert_eq!(text_frame.get_left_top(), "┍");
+/// assert_eq!(text_frame.get_left_btm(), "└");
+///
+/// let text_frame = text_frame.line_styles(line_styles.left(LineStyle::Double));
+/// assert_eq!(text_frame.get_left_top(), "╓");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct LineStyles {
+    top: LineStyle,
+    btm: LineStyle,
+    left: LineStyle,
+    right: LineStyle,
+}
+
+impl LineStyles {
+    /// All four sides in the same style.
+    pub fn all(style: LineStyle) -> Self {
+        Self {
+            top: style,
+            btm: style,
+            left: style,
+            right: style,
+        }
+    }
+
+    /// Specifies the top line style.
+    pub fn top(mut self, style: LineStyle) -> Self {
+        self.top = style;
+        self
+    }
+
+    /// Specifies the bottom line style.
+    pub fn btm(mut self, style: LineStyle) -> Self {
+        self.btm = style;
+        self
+    }
+
+    /// Specifies the left line style.
+    pub fn left(mut self, style: LineStyle) -> Self {
+        self.left = style;
+        self
+    }
+
+    /// Specifies the right line style.
+    pub fn right(mut self, style: LineStyle) -> Self {
+        self.right = style;
+        self
+    }
+
+    /// Top line style.
+    #[inline]
+    pub fn get_top(&self) -> LineStyle {
+        self.top
+    }
+
+    /// Bottom line style.
+    #[inline]
+    pub fn get_btm(&self) -> LineStyle {
+        self.btm
+    }
+
+    /// Left line style.
+    #[inline]
+    pub fn get_left(&self) -> LineStyle {
+        self.left
+    }
+
+    /// Right line style.
+    #[inline]
+    pub fn get_right(&self) -> LineStyle {
+        self.right
+    }
+}
+
+impl From<LineStyles> for BorderSet {
+    fn from(styles: LineStyles) -> Self {
+        BorderSet::new()
+            .left_top(corner(
+                styles.top,
+                styles.left,
+                ['┌', '┍', '┎', '┏', '╒', '╓', '╔'],
+            ))
+            .top_line(hor_line(styles.top))
+            .right_top(corner(
+                styles.top,
+                styles.right,
+                ['┐', '┑', '┒', '┓', '╕', '╖', '╗'],
+            ))
+            .vert_left(vert_line(styles.left))
+            .vert_right(vert_line(styles.right))
+            .left_btm(corner(
+                styles.btm,
+                styles.left,
+                ['└', '┕', '┖', '┗', '╘', '╙', '╚'],
+            ))
+            .btm_line(hor_line(styles.btm))
+            .right_btm(corner(
+                styles.btm,
+                styles.right,
+                ['┘', '┙', '┚', '┛', '╛', '╜', '╝'],
+            ))
+    }
+}
+
+fn hor_line(style: LineStyle) -> char {
+    match style {
+        LineStyle::Light => '─',
+        LineStyle::Heavy => '━',
+        LineStyle::Double => '═',
+        LineStyle::Dashed => '┄',
+        LineStyle::None => ' ',
+    }
+}
+
+fn vert_line(style: LineStyle) -> char {
+    match style {
+        LineStyle::Light => '│',
+        LineStyle::Heavy => '┃',
+        LineStyle::Double => '║',
+        LineStyle::Dashed => '┆',
+        LineStyle::None => ' ',
+    }
+}
+
+/// Corner joining a horizontal and a vertical line.
+///
+/// The corners are ordered light, heavy horizontal, heavy vertical, heavy,
+/// double horizontal, double vertical and double. A double line wins over
+/// a heavy one, which then joins as light.
+fn corner(hor: LineStyle, vert: LineStyle, corners: [char; 7]) -> char {
+    use LineStyle::{Double, Heavy};
+
+    let idx = match (hor, vert) {
+        (Heavy, Heavy) => 3,
+        (Double, Double) => 6,
+        (Double, _) => 4,
+        (_, Double) => 5,
+        (Heavy, _) => 1,
+        (_, Heavy) => 2,
+        _ => 0,
+    };
+    corners[idx]
+}
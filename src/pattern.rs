@@ -0,0 +1,133 @@
+use crate::piece_sink::PieceSink;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Graphemes of the pattern with their widths, skipping zero-width ones.
+fn graphemes(
+    pattern: &str,
+    str_width: fn(&str) -> usize,
+) -> impl Iterator<Item = (&str, usize)> + Clone {
+    pattern
+        .graphemes(true)
+        .map(move |grapheme| (grapheme, str_width(grapheme)))
+        .filter(|(_, width)| *width > 0)
+}
+
+/// Grapheme of the pattern at the index, cycling through the `count` graphemes.
+fn grapheme_at<'a>(
+    mut graphemes: impl Iterator<Item = (&'a str, usize)>,
+    count: usize,
+    idx: usize,
+) -> (&'a str, usize) {
+    graphemes
+        .nth(idx % count)
+        .expect("index within the grapheme count")
+}
+
+/// Width of the widest grapheme of the pattern.
+pub(crate) fn pattern_width(pattern: &str, str_width: fn(&str) -> usize) -> usize {
+    graphemes(pattern, str_width)
+        .map(|(_, width)| width)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Repeats the pattern over the width.
+///
+/// The right half mirrors the left half, so both ends of the line match.
+/// Columns a grapheme does not fit in are padded with spaces in the middle.
+pub(crate) fn push_hor_pattern<'a>(
+    pieces: &mut impl PieceSink<'a>,
+    pattern: &'a str,
+    width: usize,
+    str_width: fn(&str) -> usize,
+) {
+    let graphemes = graphemes(pattern, str_width);
+    let len = graphemes.clone().count();
+    if len == 0 {
+        pieces.push_repeat(" ", width);
+        return;
+    }
+
+    let mut half_width = 0;
+    let mut count = 0;
+    for (grapheme, grapheme_width) in graphemes.clone().cycle() {
+        if half_width + grapheme_width > width / 2 {
+            break;
+        }
+        pieces.push(grapheme);
+        half_width += grapheme_width;
+        count += 1;
+    }
+
+    let middle = width - half_width * 2;
+    let (grapheme, grapheme_width) = grapheme_at(graphemes.clone(), len, count);
+    if grapheme_width <= middle {
+        let pad = middle - grapheme_width;
+        pieces.push_repeat(" ", pad / 2);
+        pieces.push(grapheme);
+        pieces.push_repeat(" ", pad - pad / 2);
+    } else {
+        pieces.push_repeat(" ", middle);
+    }
+
+    for idx in (0..count).rev() {
+        pieces.push(grapheme_at(graphemes.clone(), len, idx).0);
+    }
+}
+
+/// Grapheme of the pattern for a row of a vertical line.
+///
+/// The lower half mirrors the upper half, padded with spaces to the width.
+pub(crate) fn push_vert_pattern<'a>(
+    pieces: &mut impl PieceSink<'a>,
+    pattern: &'a str,
+    (row, rows): (usize, usize),
+    width: usize,
+    str_width: fn(&str) -> usize,
+) {
+    let graphemes = graphemes(pattern, str_width);
+    let len = graphemes.clone().count();
+    if len == 0 {
+        pieces.push_repeat(" ", width);
+        return;
+    }
+
+    let idx = row.min(rows.saturating_sub(row + 1));
+    let (grapheme, grapheme_width) = grapheme_at(graphemes, len, idx);
+    pieces.push(grapheme);
+    pieces.push_repeat(" ", width.saturating_sub(grapheme_width));
+}
+
+/// Repeats the pattern over the width, anchored to the frame coordinates.
+///
+/// Each grapheme takes a cell as wide as the widest one. The cell at column `x`
+/// of row `y` holds the grapheme at the cell index plus `shift` times the row.
+/// Cells cut off at either end are padded with spaces.
+pub(crate) fn push_fill_pattern<'a>(
+    pieces: &mut impl PieceSink<'a>,
+    pattern: &'a str,
+    (x, y): (usize, usize),
+    shift: isize,
+    width: usize,
+    str_width: fn(&str) -> usize,
+) {
+    let graphemes = graphemes(pattern, str_width);
+    let len = graphemes.clone().count();
+    let Some(cell) = graphemes.clone().map(|(_, width)| width).max() else {
+        pieces.push_repeat(" ", width);
+        return;
+    };
+
+    let end = x + width;
+    let mut col = x.next_multiple_of(cell).min(end);
+    pieces.push_repeat(" ", col - x);
+    while col + cell <= end {
+        let idx = (col / cell) as isize + shift * y as isize;
+        let idx = idx.rem_euclid(len as isize) as usize;
+        let (grapheme, grapheme_width) = grapheme_at(graphemes.clone(), len, idx);
+        pieces.push(grapheme);
+        pieces.push_repeat(" ", cell - grapheme_width);
+        col += cell;
+    }
+    pieces.push_repeat(" ", end - col);
+}
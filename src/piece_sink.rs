@@ -0,0 +1,185 @@
+use core::{fmt, iter};
+use std::io;
+
+/// Receiver of the pieces of a frame, as they are rendered.
+pub(crate) trait PieceSink<'a> {
+    fn push(&mut self, piece: &'a str);
+
+    /// Pushes the piece `count` times.
+    fn push_repeat(&mut self, piece: &'a str, count: usize) {
+        for _ in 0..count {
+            self.push(piece);
+        }
+    }
+
+    /// Whether writing failed, so rendering can stop early.
+    fn failed(&self) -> bool {
+        false
+    }
+}
+
+impl<'a> PieceSink<'a> for Vec<&'a str> {
+    #[inline]
+    fn push(&mut self, piece: &'a str) {
+        Vec::push(self, piece);
+    }
+
+    #[inline]
+    fn push_repeat(&mut self, piece: &'a str, count: usize) {
+        self.extend(iter::repeat_n(piece, count));
+    }
+}
+
+/// Counts the bytes of the pieces.
+#[derive(Debug, Default)]
+pub(crate) struct ByteCount(pub(crate) usize);
+
+impl PieceSink<'_> for ByteCount {
+    #[inline]
+    fn push(&mut self, piece: &str) {
+        self.0 += piece.len();
+    }
+
+    #[inline]
+    fn push_repeat(&mut self, piece: &str, count: usize) {
+        self.0 += piece.len() * count;
+    }
+}
+
+/// Buffer size of [`IoSink`] and [`FmtSink`], so the writer sees a few large writes.
+const IO_BUF_LEN: usize = 4 * 1024;
+
+/// Writes the pieces to an [`io::Write`] through a buffer on the stack.
+pub(crate) struct IoSink<'w, W: io::Write + ?Sized> {
+    out: &'w mut W,
+    buf: [u8; IO_BUF_LEN],
+    buf_len: usize,
+    written: usize,
+    err: Option<io::Error>,
+}
+
+impl<'w, W: io::Write + ?Sized> IoSink<'w, W> {
+    pub(crate) fn new(out: &'w mut W) -> Self {
+        Self {
+            out,
+            buf: [0; IO_BUF_LEN],
+            buf_len: 0,
+            written: 0,
+            err: None,
+        }
+    }
+
+    fn write(out: &mut W, bytes: &[u8], written: &mut usize) -> Option<io::Error> {
+        let err = out.write_all(bytes).err();
+        if err.is_none() {
+            *written += bytes.len();
+        }
+        err
+    }
+
+    fn flush_buf(&mut self) {
+        if self.buf_len > 0 {
+            let bytes = &self.buf[..self.buf_len];
+            self.err = Self::write(self.out, bytes, &mut self.written);
+            self.buf_len = 0;
+        }
+    }
+
+    /// Writes the buffered pieces, returning the number of bytes written.
+    pub(crate) fn finish(mut self) -> io::Result<usize> {
+        if self.err.is_none() {
+            self.flush_buf();
+        }
+        match self.err {
+            Some(err) => Err(err),
+            None => Ok(self.written),
+        }
+    }
+}
+
+impl<W: io::Write + ?Sized> PieceSink<'_> for IoSink<'_, W> {
+    fn push(&mut self, piece: &str) {
+        if self.err.is_some() {
+            return;
+        }
+
+        let bytes = piece.as_bytes();
+        if self.buf_len + bytes.len() > IO_BUF_LEN {
+            self.flush_buf();
+        }
+        if bytes.len() > IO_BUF_LEN {
+            if self.err.is_none() {
+                self.err = Self::write(self.out, bytes, &mut self.written);
+            }
+        } else if self.err.is_none() {
+            self.buf[self.buf_len..self.buf_len + bytes.len()].copy_from_slice(bytes);
+            self.buf_len += bytes.len();
+        }
+    }
+
+    fn failed(&self) -> bool {
+        self.err.is_some()
+    }
+}
+
+/// Writes the pieces to a [`fmt::Write`] through a buffer on the stack.
+pub(crate) struct FmtSink<'w, W: fmt::Write + ?Sized> {
+    out: &'w mut W,
+    buf: [u8; IO_BUF_LEN],
+    buf_len: usize,
+    result: fmt::Result,
+}
+
+impl<'w, W: fmt::Write + ?Sized> FmtSink<'w, W> {
+    pub(crate) fn new(out: &'w mut W) -> Self {
+        Self {
+            out,
+            buf: [0; IO_BUF_LEN],
+            buf_len: 0,
+            result: Ok(()),
+        }
+    }
+
+    fn flush_buf(&mut self) {
+        if self.buf_len > 0 {
+            // The buffer only ever ends on a piece boundary.
+            let text =
+                core::str::from_utf8(&self.buf[..self.buf_len]).expect("whole pieces are buffered");
+            self.result = self.out.write_str(text);
+            self.buf_len = 0;
+        }
+    }
+
+    /// Writes the buffered pieces.
+    pub(crate) fn finish(mut self) -> fmt::Result {
+        if self.result.is_ok() {
+            self.flush_buf();
+        }
+        self.result
+    }
+}
+
+impl<W: fmt::Write + ?Sized> PieceSink<'_> for FmtSink<'_, W> {
+    fn push(&mut self, piece: &str) {
+        if self.result.is_err() {
+            return;
+        }
+
+        let bytes = piece.as_bytes();
+        if self.buf_len + bytes.len() > IO_BUF_LEN {
+            self.flush_buf();
+        }
+        if bytes.len() > IO_BUF_LEN {
+            if self.result.is_ok() {
+                self.result = self.out.write_str(piece);
+            }
+        } else if self.result.is_ok() {
+            self.buf[self.buf_len..self.buf_len + bytes.len()].copy_from_slice(bytes);
+            self.buf_len += bytes.len();
+        }
+    }
+
+    fn failed(&self) -> bool {
+        self.result.is_err()
+    }
+}
@@ -0,0 +1,59 @@
+use crate::txt_frame::RowCache;
+use crate::TextFrame;
+use std::io;
+
+/// A frame of fixed width, ready to render many texts.
+///
+/// Created by [`TextFrame::prepare`]. Texts wider than the frame,
+/// or frames with rows that differ by position, are rendered as usual.
+///
+/// # Examples
+///
+/// ```
+/// # use txtframe::*;
+/// let text_frame = TextFrame::new();
+/// let prepared = text_frame.prepare(6);
+///
+/// assert_eq!(
+///     prepared.render("ab"),
+///     text_frame.clone().width(6).frame_iter("ab").collect::<String>()
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PreparedFrame {
+    frame: TextFrame,
+    cache: Option<RowCache>,
+}
+
+impl PreparedFrame {
+    pub(crate) fn new(frame: TextFrame) -> Self {
+        let cache = frame.row_cache();
+        Self { frame, cache }
+    }
+
+    /// Renders the text in the frame.
+    pub fn render(&self, text: &str) -> String {
+        let mut out = String::new();
+        if let Some(cache) = &self.cache {
+            if self.frame.render_cached(text, cache, &mut out) {
+                return out;
+            }
+        }
+
+        out.extend(self.frame.frame_iter(text));
+        out
+    }
+
+    /// Writes the text in the frame, returning the number of bytes written.
+    pub fn write_to(&self, text: &str, out: &mut impl io::Write) -> io::Result<usize> {
+        let frame = self.render(text);
+        out.write_all(frame.as_bytes())?;
+        Ok(frame.len())
+    }
+
+    /// Frame settings.
+    #[inline]
+    pub fn get_frame(&self) -> &TextFrame {
+        &self.frame
+    }
+}
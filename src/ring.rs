@@ -0,0 +1,105 @@
+use crate::BorderSet;
+#[cfg(feature = "color")]
+use crate::Color;
+
+/// A border drawn inside the frame, around the text.
+///
+/// Rings are nested from the outside in, each separated from the enclosing
+/// border by its gap. The gap is filled with the frame fill, the fill of the
+/// ring border set is not used.
+///
+/// # Examples
+///
+/// ```
+/// # use txtframe::*;
+/// let text_frame = TextFrame::new()
+///     .frame_var(&FrameVar::Double)
+///     .ring(Ring::new(&FrameVar::Light.into()).gap(1));
+///
+/// assert_eq!(text_frame.get_rings().len(), 1);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct Ring {
+    pub(crate) border: BorderSet,
+    pub(crate) gap: usize,
+    #[cfg(feature = "color")]
+    pub(crate) color: Color,
+    #[cfg(all(feature = "serde", not(feature = "color")))]
+    #[serde(skip_serializing)]
+    pub(crate) color: crate::serde_impl::Ignored,
+}
+
+impl Ring {
+    /// Construct a ring with the border pieces.
+    pub fn new(border: &BorderSet) -> Self {
+        Self {
+            border: border.clone(),
+            ..Self::default()
+        }
+    }
+
+    /// Specifies the border pieces.
+    pub fn border(mut self, border: &BorderSet) -> Self {
+        self.border = border.clone();
+        self
+    }
+
+    /// Change border pieces.
+    #[inline]
+    pub fn set_border(&mut self, border: &BorderSet) -> &mut Self {
+        self.border = border.clone();
+        self
+    }
+
+    /// Border pieces.
+    #[inline]
+    pub fn get_border(&self) -> &BorderSet {
+        &self.border
+    }
+
+    /// Specifies the gap to the enclosing border.
+    pub fn gap(mut self, gap: usize) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Change gap to the enclosing border.
+    #[inline]
+    pub fn set_gap(&mut self, gap: usize) -> &mut Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Gap to the enclosing border.
+    #[inline]
+    pub fn get_gap(&self) -> usize {
+        self.gap
+    }
+
+    /// Specifies the ring color, the frame color by default.
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Change ring color.
+    #[inline]
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn set_color(&mut self, color: Color) -> &mut Self {
+        self.color = color;
+        self
+    }
+
+    /// Ring color.
+    #[inline]
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn get_color(&self) -> Color {
+        self.color
+    }
+}
@@ -0,0 +1,142 @@
+#[cfg(feature = "color")]
+use crate::Attr;
+use crate::Piece;
+use crate::Sides;
+use core::fmt;
+#[cfg(not(feature = "color"))]
+use serde::de::IgnoredAny;
+use serde::de::{self, SeqAccess, Visitor};
+use serde::ser::SerializeSeq;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "color")]
+const ATTR_NAMES: &[&str] = &["bold", "dim", "italic", "underline", "blink", "reverse"];
+#[cfg(feature = "color")]
+const ATTRS: [Attr; 6] = [
+    Attr::BOLD,
+    Attr::DIM,
+    Attr::ITALIC,
+    Attr::UNDERLINE,
+    Attr::BLINK,
+    Attr::REVERSE,
+];
+
+const SIDES_NAMES: &[&str] = &["top", "btm", "left", "right"];
+const SIDES: [Sides; 4] = [Sides::TOP, Sides::BTM, Sides::LEFT, Sides::RIGHT];
+
+/// Setting of the `color` feature, such as a color, text attribute or highlight,
+/// read and ignored in builds without it.
+#[cfg(not(feature = "color"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Ignored;
+
+#[cfg(not(feature = "color"))]
+impl<'de> Deserialize<'de> for Ignored {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        IgnoredAny::deserialize(deserializer).map(|_| Ignored)
+    }
+}
+
+/// Border pieces stored as a non-empty string.
+pub(crate) mod glyph {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        glyph: &Piece,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(glyph)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Piece, D::Error> {
+        deserializer.deserialize_str(GlyphVisitor)
+    }
+
+    struct GlyphVisitor;
+
+    impl Visitor<'_> for GlyphVisitor {
+        type Value = Piece;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a non-empty string")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            match value.is_empty() {
+                false => Ok(value.into()),
+                true => Err(E::invalid_value(de::Unexpected::Str(value), &self)),
+            }
+        }
+    }
+}
+
+/// Flags stored as a list of names, e.g. `["bold", "underline"]`.
+macro_rules! flags_serde {
+    ($flags:ty, $none:expr, $names:expr, $values:expr, $expecting:literal) => {
+        impl Serialize for $flags {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let names = $names
+                    .iter()
+                    .zip($values)
+                    .filter(|(_, flag)| self.contains(*flag))
+                    .map(|(name, _)| name);
+                let mut seq = serializer.serialize_seq(None)?;
+                for name in names {
+                    seq.serialize_element(name)?;
+                }
+                seq.end()
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $flags {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct FlagsVisitor;
+
+                impl<'de> Visitor<'de> for FlagsVisitor {
+                    type Value = $flags;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                        formatter.write_str($expecting)
+                    }
+
+                    fn visit_seq<A: SeqAccess<'de>>(
+                        self,
+                        mut seq: A,
+                    ) -> Result<Self::Value, A::Error> {
+                        let mut flags = $none;
+                        while let Some(name) = seq.next_element::<String>()? {
+                            flags |= $names
+                                .iter()
+                                .zip($values)
+                                .find(|(flag_name, _)| **flag_name == name)
+                                .map(|(_, flag)| flag)
+                                .ok_or_else(|| de::Error::unknown_variant(&name, $names))?;
+                        }
+                        Ok(flags)
+                    }
+                }
+
+                deserializer.deserialize_seq(FlagsVisitor)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "color")]
+flags_serde!(
+    Attr,
+    Attr::NONE,
+    ATTR_NAMES,
+    ATTRS,
+    "a list of text attributes"
+);
+
+flags_serde!(
+    Sides,
+    Sides::NONE,
+    SIDES_NAMES,
+    SIDES,
+    "a list of frame sides"
+);
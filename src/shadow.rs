@@ -0,0 +1,195 @@
+#[cfg(feature = "color")]
+use crate::Color;
+use crate::Piece;
+
+/// Corner of the frame the shadow falls to.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ShadowDir {
+    /// Shadow right of and below the frame.
+    #[default]
+    RightBtm,
+    /// Shadow left of and below the frame.
+    LeftBtm,
+    /// Shadow right of and above the frame.
+    RightTop,
+    /// Shadow left of and above the frame.
+    LeftTop,
+}
+
+/// A drop shadow beside the frame.
+///
+/// The shadow adds a column and a row, offset by one cell from the frame.
+///
+/// # Examples
+///
+/// ```
+/// # use txtframe::*;
+/// let text_frame = TextFrame::new().shadow(Shadow::new());
+/// # #[cfg(not(feature = "color"))]
+/// assert_eq!(
+///     text_frame.frame_iter("ab").collect::<String>(),
+///     "┌──┐\n│ab│░\n└──┘░\n ░░░░"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default = "Shadow::new", deny_unknown_fields))]
+pub struct Shadow {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impl::glyph"))]
+    pub(crate) glyph: Piece,
+    pub(crate) dir: ShadowDir,
+    #[cfg(feature = "color")]
+    pub(crate) color: Color,
+    #[cfg(all(feature = "serde", not(feature = "color")))]
+    #[serde(skip_serializing)]
+    pub(crate) color: crate::serde_impl::Ignored,
+    #[cfg(feature = "color")]
+    pub(crate) color_bg: Color,
+    #[cfg(all(feature = "serde", not(feature = "color")))]
+    #[serde(skip_serializing)]
+    pub(crate) color_bg: crate::serde_impl::Ignored,
+}
+
+impl Default for Shadow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Shadow {
+    /// Construct a shade shadow to the bottom right.
+    pub fn new() -> Self {
+        Self {
+            glyph: '░'.into(),
+            dir: ShadowDir::RightBtm,
+            #[cfg(feature = "color")]
+            color: Color::Default,
+            #[cfg(all(feature = "serde", not(feature = "color")))]
+            color: crate::serde_impl::Ignored,
+            #[cfg(feature = "color")]
+            color_bg: Color::Default,
+            #[cfg(all(feature = "serde", not(feature = "color")))]
+            color_bg: crate::serde_impl::Ignored,
+        }
+    }
+
+    /// Construct a shadow of blank cells on a background color.
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn bg(color: Color) -> Self {
+        Self::new().glyph(' ').color_bg(color)
+    }
+
+    /// Specifies the shadow glyph.
+    pub fn glyph(mut self, glyph: impl Into<Piece>) -> Self {
+        self.glyph = glyph.into();
+        self
+    }
+
+    /// Change shadow glyph.
+    #[inline]
+    pub fn set_glyph(&mut self, glyph: impl Into<Piece>) -> &mut Self {
+        self.glyph = glyph.into();
+        self
+    }
+
+    /// Shadow glyph.
+    #[inline]
+    pub fn get_glyph(&self) -> &str {
+        &self.glyph
+    }
+
+    /// Specifies the shadow direction.
+    pub fn dir(mut self, dir: ShadowDir) -> Self {
+        self.dir = dir;
+        self
+    }
+
+    /// Change shadow direction.
+    #[inline]
+    pub fn set_dir(&mut self, dir: ShadowDir) -> &mut Self {
+        self.dir = dir;
+        self
+    }
+
+    /// Shadow direction.
+    #[inline]
+    pub fn get_dir(&self) -> ShadowDir {
+        self.dir
+    }
+
+    /// Specifies the glyph color.
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Change glyph color.
+    #[inline]
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn set_color(&mut self, color: Color) -> &mut Self {
+        self.color = color;
+        self
+    }
+
+    /// Glyph color.
+    #[inline]
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn get_color(&self) -> Color {
+        self.color
+    }
+
+    /// Specifies the background color.
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn color_bg(mut self, color: Color) -> Self {
+        self.color_bg = color;
+        self
+    }
+
+    /// Change background color.
+    #[inline]
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn set_color_bg(&mut self, color: Color) -> &mut Self {
+        self.color_bg = color;
+        self
+    }
+
+    /// Background color.
+    #[inline]
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn get_color_bg(&self) -> Color {
+        self.color_bg
+    }
+
+    /// Shadow on the left side.
+    pub(crate) fn is_left(&self) -> bool {
+        matches!(self.dir, ShadowDir::LeftBtm | ShadowDir::LeftTop)
+    }
+
+    /// Shadow on the top side.
+    pub(crate) fn is_top(&self) -> bool {
+        matches!(self.dir, ShadowDir::RightTop | ShadowDir::LeftTop)
+    }
+
+    /// Escape codes before the shadow glyphs.
+    #[cfg(feature = "color")]
+    pub(crate) fn esc_iter(&self) -> impl Iterator<Item = &'static str> {
+        let fg = (self.color != Color::Default).then(|| self.color.into_fg_str());
+        let bg = (self.color_bg != Color::Default).then(|| self.color_bg.into_bg_str());
+        fg.into_iter().chain(bg)
+    }
+
+    #[cfg(not(feature = "color"))]
+    pub(crate) fn esc_iter(&self) -> impl Iterator<Item = &'static str> {
+        core::iter::empty()
+    }
+}
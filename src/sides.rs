@@ -0,0 +1,70 @@
+use core::ops::{BitOr, BitOrAssign, Sub};
+
+/// Set of visible frame sides.
+///
+/// Sides are combined with `|` and removed with `-`.
+///
+/// # Examples
+///
+/// ```
+/// # use txtframe::*;
+/// let text_frame = TextFrame::new().sides(Sides::TOP | Sides::BTM);
+///
+/// assert!(!text_frame.get_sides().contains(Sides::LEFT));
+/// assert_eq!(Sides::ALL - Sides::LEFT - Sides::RIGHT, Sides::TOP | Sides::BTM);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Sides(u8);
+
+impl Default for Sides {
+    fn default() -> Self {
+        Sides::ALL
+    }
+}
+
+impl Sides {
+    /// No sides.
+    pub const NONE: Sides = Sides(0);
+    /// Top side.
+    pub const TOP: Sides = Sides(1);
+    /// Bottom side.
+    pub const BTM: Sides = Sides(1 << 1);
+    /// Left side.
+    pub const LEFT: Sides = Sides(1 << 2);
+    /// Right side.
+    pub const RIGHT: Sides = Sides(1 << 3);
+    /// All four sides.
+    pub const ALL: Sides = Sides(0b1111);
+
+    /// Checks if all sides of `other` are set.
+    pub fn contains(self, other: Sides) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Checks if no side is set.
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl BitOr for Sides {
+    type Output = Sides;
+
+    fn bitor(self, rhs: Sides) -> Sides {
+        Sides(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for Sides {
+    fn bitor_assign(&mut self, rhs: Sides) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl Sub for Sides {
+    type Output = Sides;
+
+    fn sub(self, rhs: Sides) -> Sides {
+        Sides(self.0 & !rhs.0)
+    }
+}
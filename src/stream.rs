@@ -0,0 +1,247 @@
+use crate::txt_frame::Layout;
+use crate::{Shadow, TextFrame};
+use core::iter;
+use std::io;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Handling of lines wider than a streamed frame.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Overflow {
+    /// Continue the line on the next rows.
+    #[default]
+    Wrap,
+    /// Cut the line at the frame side.
+    Truncate,
+}
+
+/// Rows of a frame around streamed lines, created by [`TextFrame::stream_reader`]
+/// and [`TextFrame::stream_lines`].
+///
+/// Only the current line is kept, so memory does not grow with the text.
+/// Vertical patterns count from the top edge down and from the bottom edge up.
+/// With a shadow above the frame, each text row waits for the next line,
+/// since the last row of the frame casts no shadow beside it.
+///
+/// # Examples
+///
+/// ```
+/// # use txtframe::*;
+/// let text_frame = TextFrame::new();
+/// let reader = "abcdef\ngh".as_bytes();
+/// let rows = text_frame
+///     .stream_reader(6, Overflow::Wrap, reader)
+///     .collect::<std::io::Result<Vec<_>>>()
+///     .unwrap();
+/// # #[cfg(not(feature = "color"))]
+/// assert_eq!(rows, ["┌────┐", "│abcd│", "│ef  │", "│gh  │", "└────┘"]);
+/// ```
+#[derive(Debug)]
+pub struct FrameStream<I> {
+    frame: TextFrame,
+    overflow: Overflow,
+    source: I,
+    empty: Layout,
+    open: Layout,
+    tail: Option<Layout>,
+    /// Next text row, read ahead to tell whether the current one is the last.
+    peeked: Option<Option<io::Result<(usize, usize)>>>,
+    line: String,
+    pos: Option<usize>,
+    lines: usize,
+    idx: usize,
+    done: bool,
+}
+
+impl<I: Iterator<Item = io::Result<String>>> FrameStream<I> {
+    pub(crate) fn new(frame: TextFrame, overflow: Overflow, source: I) -> Self {
+        let empty = frame.empty_layout();
+        // Rows are laid out for a text too long to end, until the source runs out
+        // and the tail layout with the actual line count takes over.
+        let open = empty.add_lines(usize::MAX / 4);
+        Self {
+            frame,
+            overflow,
+            source,
+            empty,
+            open,
+            tail: None,
+            peeked: None,
+            line: String::new(),
+            pos: None,
+            lines: 0,
+            idx: 0,
+            done: false,
+        }
+    }
+
+    /// Writes all rows separated by newlines, returning the number of bytes written.
+    pub fn write_to(self, out: &mut impl io::Write) -> io::Result<usize> {
+        let mut len = 0;
+        for (idx, row) in self.enumerate() {
+            let row = row?;
+            if idx > 0 {
+                out.write_all(b"\n")?;
+                len += 1;
+            }
+            out.write_all(row.as_bytes())?;
+            len += row.len();
+        }
+        Ok(len)
+    }
+
+    /// Most lines the next row reads from the source.
+    pub(crate) fn lines_needed(&self) -> usize {
+        if self.done || self.tail.is_some() {
+            return 0;
+        }
+
+        let mut wants_line = false;
+        let lines = &mut iter::from_fn(|| {
+            wants_line = true;
+            None
+        });
+        self.frame.row_at(&self.open, self.idx, lines);
+        if !wants_line {
+            return 0;
+        }
+
+        let current = usize::from(self.peeked.is_none() && self.pos.is_none());
+        current + usize::from(self.peeks())
+    }
+
+    /// Whether text rows read the next one ahead.
+    fn peeks(&self) -> bool {
+        self.frame.get_shadow().is_some_and(Shadow::is_top)
+    }
+
+    pub(crate) fn source_mut(&mut self) -> &mut I {
+        &mut self.source
+    }
+
+    /// Byte range of the next text row in `line`, reading lines as needed.
+    fn next_chunk(&mut self) -> Option<io::Result<(usize, usize)>> {
+        if let Some(chunk) = self.peeked.take() {
+            return chunk;
+        }
+
+        let width = self.open.text_width();
+        let str_width = self.open.str_width();
+
+        loop {
+            let Some(pos) = self.pos else {
+                match self.source.next()? {
+                    Ok(line) => {
+                        self.line = line;
+                        self.pos = Some(0);
+                        continue;
+                    }
+                    Err(err) => return Some(Err(err)),
+                }
+            };
+
+            let rest = &self.line[pos..];
+            let end = pos + fit_len(rest, width, str_width);
+            let next = match self.overflow {
+                Overflow::Wrap => Some(end).filter(|end| *end < self.line.len()),
+                Overflow::Truncate => None,
+            };
+
+            if end == pos && !rest.is_empty() && self.overflow == Overflow::Wrap {
+                // A grapheme wider than the frame is dropped rather than break the width.
+                let skip = rest.graphemes(true).next().map_or(rest.len(), str::len);
+                self.pos = Some(pos + skip).filter(|pos| *pos < self.line.len());
+                if self.pos.is_none() {
+                    return Some(Ok((pos, pos)));
+                }
+                continue;
+            }
+
+            self.pos = next;
+            return Some(Ok((pos, end)));
+        }
+    }
+}
+
+impl<I: Iterator<Item = io::Result<String>>> Iterator for FrameStream<I> {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut wants_line = false;
+        let layout = self.tail.as_ref().unwrap_or(&self.open);
+        let lines = &mut iter::from_fn(|| {
+            wants_line = true;
+            None
+        });
+        let mut row = self.frame.row_at(layout, self.idx, lines);
+
+        let mut range = None;
+        if wants_line && self.tail.is_none() {
+            match self.next_chunk() {
+                Some(Ok(chunk)) => range = Some(chunk),
+                Some(Err(err)) => {
+                    self.done = true;
+                    return Some(Err(err));
+                }
+                None => {
+                    let tail = self.empty.add_lines(self.lines);
+                    row = self.frame.row_at(&tail, self.idx, &mut iter::empty());
+                    self.tail = Some(tail);
+                }
+            }
+        }
+
+        let Some(row) = row else {
+            self.done = true;
+            return None;
+        };
+
+        let peeked_line;
+        let mut pieces = Vec::new();
+        match range {
+            Some((start, end)) => {
+                let line = match self.peeks() {
+                    true => {
+                        peeked_line = self.line[start..end].to_owned();
+                        let peeked = self.next_chunk();
+                        if peeked.is_none() {
+                            self.tail = Some(self.empty.add_lines(self.lines + 1));
+                        }
+                        self.peeked = Some(peeked);
+                        &peeked_line
+                    }
+                    false => &self.line[start..end],
+                };
+                let layout = self.tail.as_ref().unwrap_or(&self.open);
+                let row = self.frame.row_at(layout, self.idx, &mut iter::once(line))?;
+                self.frame.push_line(layout, self.idx, row, &mut pieces);
+                self.lines += 1;
+            }
+            None => {
+                let layout = self.tail.as_ref().unwrap_or(&self.open);
+                self.frame.push_line(layout, self.idx, row, &mut pieces);
+            }
+        }
+        self.idx += 1;
+        Some(Ok(pieces.concat()))
+    }
+}
+
+impl<I: Iterator<Item = io::Result<String>>> iter::FusedIterator for FrameStream<I> {}
+
+/// Byte length of the longest grapheme prefix of the text fitting the width.
+fn fit_len(text: &str, width: usize, str_width: fn(&str) -> usize) -> usize {
+    let mut sum = 0;
+    text.grapheme_indices(true)
+        .take_while(|(_, grapheme)| {
+            sum += str_width(grapheme);
+            sum <= width
+        })
+        .last()
+        .map_or(0, |(idx, grapheme)| idx + grapheme.len())
+}
@@ -0,0 +1,180 @@
+use crate::{Attr, Color, FrameVar};
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
+static REGISTRY: OnceLock<RwLock<HashMap<String, Theme>>> = OnceLock::new();
+
+/// Frame style with glyphs, colors, attributes and padding.
+///
+/// # Examples
+///
+/// ```
+/// # use txtframe::*;
+/// let deploy = Theme::new()
+///     .frame_var(FrameVar::Round)
+///     .color_fra(Color::Magenta)
+///     .attr_txt(Attr::BOLD)
+///     .expand_width(2);
+/// Theme::register("deploy", deploy);
+///
+/// let text_frame = TextFrame::new().theme(&Theme::named("deploy").unwrap());
+/// println!("{}", text_frame.frame_iter("Deployed").collect::<String>());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default = "Theme::new", deny_unknown_fields))]
+pub struct Theme {
+    frame_var: FrameVar,
+    color_fra: Color,
+    color_txt: Color,
+    color_fill: Color,
+    attr_txt: Attr,
+    expand_width: usize,
+    expand_height: usize,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Theme {
+    /// Construct a light frame theme without colors.
+    pub fn new() -> Self {
+        Self {
+            frame_var: FrameVar::Light,
+            color_fra: Color::Default,
+            color_txt: Color::Default,
+            color_fill: Color::Default,
+            attr_txt: Attr::NONE,
+            expand_width: 0,
+            expand_height: 0,
+        }
+    }
+
+    /// Blue round frame for informational messages.
+    pub fn info() -> Self {
+        Self::new()
+            .frame_var(FrameVar::Round)
+            .color_fra(Color::Blue)
+            .expand_width(1)
+    }
+
+    /// Yellow heavy frame with bold text for warnings.
+    pub fn warning() -> Self {
+        Self::new()
+            .frame_var(FrameVar::Heavy)
+            .color_fra(Color::Yellow)
+            .color_txt(Color::Yellow)
+            .attr_txt(Attr::BOLD)
+            .expand_width(1)
+    }
+
+    /// Red double frame with bold text for errors.
+    pub fn error() -> Self {
+        Self::new()
+            .frame_var(FrameVar::Double)
+            .color_fra(Color::Red)
+            .color_txt(Color::Red)
+            .attr_txt(Attr::BOLD)
+            .expand_width(1)
+    }
+
+    /// Green round frame for successful results.
+    pub fn success() -> Self {
+        Self::new()
+            .frame_var(FrameVar::Round)
+            .color_fra(Color::Green)
+            .color_txt(Color::Green)
+            .expand_width(1)
+    }
+
+    /// Light frame with dim text for secondary output.
+    pub fn muted() -> Self {
+        Self::new()
+            .color_fra(Color::White)
+            .attr_txt(Attr::DIM)
+            .expand_width(1)
+    }
+
+    /// Looks up a registered theme, then a preset, by name.
+    pub fn named(name: &str) -> Option<Self> {
+        let registered = REGISTRY
+            .get()
+            .and_then(|registry| registry.read().ok()?.get(name).copied());
+
+        registered.or(match name {
+            "info" => Some(Self::info()),
+            "warning" => Some(Self::warning()),
+            "error" => Some(Self::error()),
+            "success" => Some(Self::success()),
+            "muted" => Some(Self::muted()),
+            _ => None,
+        })
+    }
+
+    /// Registers a theme under the name, returning the theme it replaces.
+    ///
+    /// Registered themes take precedence over presets of the same name.
+    pub fn register(name: &str, theme: Theme) -> Option<Self> {
+        REGISTRY
+            .get_or_init(Default::default)
+            .write()
+            .unwrap_or_else(|err| err.into_inner())
+            .insert(name.into(), theme)
+    }
+
+    /// Specifies the preset frame.
+    pub fn frame_var(mut self, vars: FrameVar) -> Self {
+        self.frame_var = vars;
+        self
+    }
+
+    /// Specifies the frame color.
+    pub fn color_fra(mut self, color: Color) -> Self {
+        self.color_fra = color;
+        self
+    }
+
+    /// Specifies the text color.
+    pub fn color_txt(mut self, color: Color) -> Self {
+        self.color_txt = color;
+        self
+    }
+
+    /// Specifies the fill color.
+    pub fn color_fill(mut self, color: Color) -> Self {
+        self.color_fill = color;
+        self
+    }
+
+    /// Specifies the text attributes.
+    pub fn attr_txt(mut self, attr: Attr) -> Self {
+        self.attr_txt = attr;
+        self
+    }
+
+    /// Value for the width of the frame extension.
+    pub fn expand_width(mut self, width: usize) -> Self {
+        self.expand_width = width;
+        self
+    }
+
+    /// Value for the height of the frame extension.
+    pub fn expand_height(mut self, height: usize) -> Self {
+        self.expand_height = height;
+        self
+    }
+
+    pub(crate) fn apply(&self, frame: &mut crate::TextFrame) {
+        frame
+            .set_frame_var(&self.frame_var)
+            .set_color_fra(self.color_fra)
+            .set_color_txt(self.color_txt)
+            .set_color_fill(self.color_fill)
+            .set_attr_txt(self.attr_txt)
+            .set_expand_width(self.expand_width)
+            .set_expand_height(self.expand_height);
+    }
+}
@@ -1,11 +1,28 @@
-use crate::Algn;
+use crate::display::FrameDisplay;
+use crate::frame_iter::FrameIter;
+use crate::frame_line::FrameLine;
+use crate::frame_size::{FrameRect, FrameSize};
+use crate::frame_writer::FrameWriter;
+use crate::framed_text::FramedText;
 #[cfg(feature = "color")]
-use crate::Color;
+use crate::highlight::{highlight_line, Highlight};
+use crate::pattern::{pattern_width, push_fill_pattern, push_hor_pattern, push_vert_pattern};
+use crate::piece_sink::{ByteCount, FmtSink, IoSink, PieceSink};
+use crate::prepared_frame::PreparedFrame;
+use crate::stream::{FrameStream, Overflow};
+use crate::Algn;
 use crate::FrameVar;
-use core::iter;
-use smallstr::SmallString;
+#[cfg(feature = "color")]
+use crate::{Attr, Color, Theme};
+use crate::{
+    BorderSet, FillPattern, LinePattern, LineStyle, LineStyles, Piece, Ring, Shadow, Sides,
+};
+use core::{fmt, iter};
+use std::io::{self, BufRead};
 #[cfg(feature = "esc")]
 use strip_ansi_escapes::strip;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 /// The abstract representation of a TextFrame.
 ///
@@ -49,28 +66,105 @@ use strip_ansi_escapes::strip;
 /// ```
 ///
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(default = "TextFrame::new", deny_unknown_fields)
+)]
 pub struct TextFrame {
-    left_top_cnr: SmallString<[u8; 4]>,
-    hor_top_line: SmallString<[u8; 4]>,
-    right_top_cnr: SmallString<[u8; 4]>,
-    left_btm_cnr: SmallString<[u8; 4]>,
-    vert_left_line: SmallString<[u8; 4]>,
-    vert_right_line: SmallString<[u8; 4]>,
-    hor_btm_line: SmallString<[u8; 4]>,
-    right_btm_cnr: SmallString<[u8; 4]>,
-    fill: SmallString<[u8; 4]>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "left_top", with = "crate::serde_impl::glyph")
+    )]
+    left_top_cnr: Piece,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "top_line", with = "crate::serde_impl::glyph")
+    )]
+    hor_top_line: Piece,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "right_top", with = "crate::serde_impl::glyph")
+    )]
+    right_top_cnr: Piece,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "left_btm", with = "crate::serde_impl::glyph")
+    )]
+    left_btm_cnr: Piece,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "vert_left", with = "crate::serde_impl::glyph")
+    )]
+    vert_left_line: Piece,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "vert_right", with = "crate::serde_impl::glyph")
+    )]
+    vert_right_line: Piece,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "btm_line", with = "crate::serde_impl::glyph")
+    )]
+    hor_btm_line: Piece,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "right_btm", with = "crate::serde_impl::glyph")
+    )]
+    right_btm_cnr: Piece,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impl::glyph"))]
+    fill: Piece,
+    fill_pattern: FillPattern,
+    line_pattern: LinePattern,
     width: usize,
     height: usize,
     expand: usize,
     expand_width: usize,
     expand_height: usize,
     algn: Algn,
+    sides: Sides,
+    rings: Vec<Ring>,
+    shadow: Option<Shadow>,
     #[cfg(feature = "color")]
     color_fra: Color,
+    #[cfg(all(feature = "serde", not(feature = "color")))]
+    #[serde(skip_serializing)]
+    color_fra: crate::serde_impl::Ignored,
     #[cfg(feature = "color")]
     color_txt: Color,
+    #[cfg(all(feature = "serde", not(feature = "color")))]
+    #[serde(skip_serializing)]
+    color_txt: crate::serde_impl::Ignored,
+    #[cfg(feature = "color")]
+    color_txt_auto: bool,
+    #[cfg(all(feature = "serde", not(feature = "color")))]
+    #[serde(skip_serializing)]
+    color_txt_auto: crate::serde_impl::Ignored,
     #[cfg(feature = "color")]
     color_fill: Color,
+    #[cfg(all(feature = "serde", not(feature = "color")))]
+    #[serde(skip_serializing)]
+    color_fill: crate::serde_impl::Ignored,
+    #[cfg(feature = "color")]
+    color_bg: Color,
+    #[cfg(all(feature = "serde", not(feature = "color")))]
+    #[serde(skip_serializing)]
+    color_bg: crate::serde_impl::Ignored,
+    #[cfg(feature = "color")]
+    attr_txt: Attr,
+    #[cfg(all(feature = "serde", not(feature = "color")))]
+    #[serde(skip_serializing)]
+    attr_txt: crate::serde_impl::Ignored,
+    #[cfg(feature = "color")]
+    highlights: Vec<Highlight>,
+    #[cfg(all(feature = "serde", not(feature = "color")))]
+    #[serde(skip_serializing)]
+    highlights: crate::serde_impl::Ignored,
+    #[cfg(feature = "color")]
+    ignore_case: bool,
+    #[cfg(all(feature = "serde", not(feature = "color")))]
+    #[serde(skip_serializing)]
+    ignore_case: crate::serde_impl::Ignored,
 }
 
 impl TextFrame {
@@ -91,491 +185,465 @@ impl TextFrame {
             expand_width: 0,
             expand_height: 0,
             fill: ' '.into(),
+            fill_pattern: FillPattern::Plain,
+            line_pattern: LinePattern::Plain,
             algn: Algn::Left,
+            sides: Sides::ALL,
+            rings: Vec::new(),
+            shadow: None,
             #[cfg(feature = "color")]
             color_fra: Color::Default,
+            #[cfg(all(feature = "serde", not(feature = "color")))]
+            color_fra: crate::serde_impl::Ignored,
             #[cfg(feature = "color")]
             color_txt: Color::Default,
+            #[cfg(all(feature = "serde", not(feature = "color")))]
+            color_txt: crate::serde_impl::Ignored,
+            #[cfg(feature = "color")]
+            color_txt_auto: false,
+            #[cfg(all(feature = "serde", not(feature = "color")))]
+            color_txt_auto: crate::serde_impl::Ignored,
             #[cfg(feature = "color")]
             color_fill: Color::Default,
+            #[cfg(all(feature = "serde", not(feature = "color")))]
+            color_fill: crate::serde_impl::Ignored,
+            #[cfg(feature = "color")]
+            color_bg: Color::Default,
+            #[cfg(all(feature = "serde", not(feature = "color")))]
+            color_bg: crate::serde_impl::Ignored,
+            #[cfg(feature = "color")]
+            attr_txt: Attr::NONE,
+            #[cfg(all(feature = "serde", not(feature = "color")))]
+            attr_txt: crate::serde_impl::Ignored,
+            #[cfg(feature = "color")]
+            highlights: Vec::new(),
+            #[cfg(all(feature = "serde", not(feature = "color")))]
+            highlights: crate::serde_impl::Ignored,
+            #[cfg(feature = "color")]
+            ignore_case: false,
+            #[cfg(all(feature = "serde", not(feature = "color")))]
+            ignore_case: crate::serde_impl::Ignored,
         }
     }
 
     /// Selecting preset frames.
-    pub fn frame_var(self, vars: &FrameVar) -> Self {
-        match vars {
-            FrameVar::Double => Self {
-                left_top_cnr: '╔'.into(),
-                hor_top_line: '═'.into(),
-                right_top_cnr: '╗'.into(),
-                vert_left_line: '║'.into(),
-                vert_right_line: '║'.into(),
-                left_btm_cnr: '╚'.into(),
-                hor_btm_line: '═'.into(),
-                right_btm_cnr: '╝'.into(),
-                ..self
-            },
-
-            FrameVar::Space => Self {
-                left_top_cnr: ' '.into(),
-                hor_top_line: ' '.into(),
-                right_top_cnr: ' '.into(),
-                vert_left_line: ' '.into(),
-                vert_right_line: ' '.into(),
-                left_btm_cnr: ' '.into(),
-                hor_btm_line: ' '.into(),
-                right_btm_cnr: ' '.into(),
-                ..self
-            },
-
-            FrameVar::HorDouble => Self {
-                left_top_cnr: '╒'.into(),
-                hor_top_line: '═'.into(),
-                right_top_cnr: '╕'.into(),
-                vert_left_line: '│'.into(),
-                vert_right_line: '│'.into(),
-                left_btm_cnr: '╘'.into(),
-                hor_btm_line: '═'.into(),
-                right_btm_cnr: '╛'.into(),
-                ..self
-            },
+    pub fn frame_var(mut self, vars: &FrameVar) -> Self {
+        self.set_frame_var(vars);
+        self
+    }
 
-            FrameVar::VertDouble => Self {
-                left_top_cnr: '╓'.into(),
-                hor_top_line: '─'.into(),
-                right_top_cnr: '╖'.into(),
-                vert_left_line: '║'.into(),
-                vert_right_line: '║'.into(),
-                left_btm_cnr: '╙'.into(),
-                hor_btm_line: '─'.into(),
-                right_btm_cnr: '╜'.into(),
-                ..self
-            },
+    /// Change preset frame.
+    #[inline]
+    pub fn set_frame_var(&mut self, vars: &FrameVar) -> &mut Self {
+        let fill = core::mem::take(&mut self.fill);
+        self.set_border(&BorderSet::from(*vars));
+        self.fill = fill;
+        self
+    }
 
-            FrameVar::Heavy => Self {
-                left_top_cnr: '┏'.into(),
-                hor_top_line: '━'.into(),
-                right_top_cnr: '┓'.into(),
-                vert_left_line: '┃'.into(),
-                vert_right_line: '┃'.into(),
-                left_btm_cnr: '┗'.into(),
-                hor_btm_line: '━'.into(),
-                right_btm_cnr: '┛'.into(),
-                ..self
-            },
+    /// Specifies the side lines, picking the matching corners.
+    ///
+    /// Sides with [`LineStyle::None`] are hidden.
+    pub fn line_styles(mut self, styles: LineStyles) -> Self {
+        self.set_line_styles(styles);
+        self
+    }
 
-            FrameVar::Light => Self {
-                left_top_cnr: '┌'.into(),
-                hor_top_line: '─'.into(),
-                right_top_cnr: '┐'.into(),
-                vert_left_line: '│'.into(),
-                vert_right_line: '│'.into(),
-                left_btm_cnr: '└'.into(),
-                hor_btm_line: '─'.into(),
-                right_btm_cnr: '┘'.into(),
-                ..self
-            },
+    /// Change side lines, picking the matching corners.
+    #[inline]
+    pub fn set_line_styles(&mut self, styles: LineStyles) -> &mut Self {
+        let fill = core::mem::take(&mut self.fill);
+        self.set_border(&BorderSet::from(styles));
+        self.fill = fill;
+
+        self.sides = [
+            (Sides::TOP, styles.get_top()),
+            (Sides::BTM, styles.get_btm()),
+            (Sides::LEFT, styles.get_left()),
+            (Sides::RIGHT, styles.get_right()),
+        ]
+        .into_iter()
+        .filter(|(_, style)| *style != LineStyle::None)
+        .fold(Sides::NONE, |sides, (side, _)| sides | side);
+        self
+    }
 
-            FrameVar::VertHeavy => Self {
-                left_top_cnr: '┎'.into(),
-                hor_top_line: '─'.into(),
-                right_top_cnr: '┒'.into(),
-                vert_left_line: '┃'.into(),
-                vert_right_line: '┃'.into(),
-                left_btm_cnr: '┖'.into(),
-                hor_btm_line: '─'.into(),
-                right_btm_cnr: '┚'.into(),
-                ..self
-            },
+    /// Specifies the border pieces and fill.
+    pub fn border(mut self, border_set: &BorderSet) -> Self {
+        self.set_border(border_set);
+        self
+    }
 
-            FrameVar::HorHeavy => Self {
-                left_top_cnr: '┍'.into(),
-                hor_top_line: '━'.into(),
-                right_top_cnr: '┑'.into(),
-                vert_left_line: '│'.into(),
-                vert_right_line: '│'.into(),
-                left_btm_cnr: '┕'.into(),
-                hor_btm_line: '━'.into(),
-                right_btm_cnr: '┙'.into(),
-                ..self
-            },
+    /// Change border pieces and fill.
+    #[inline]
+    pub fn set_border(&mut self, border_set: &BorderSet) -> &mut Self {
+        self.left_top_cnr = border_set.left_top_cnr.clone();
+        self.hor_top_line = border_set.hor_top_line.clone();
+        self.right_top_cnr = border_set.right_top_cnr.clone();
+        self.vert_left_line = border_set.vert_left_line.clone();
+        self.vert_right_line = border_set.vert_right_line.clone();
+        self.left_btm_cnr = border_set.left_btm_cnr.clone();
+        self.hor_btm_line = border_set.hor_btm_line.clone();
+        self.right_btm_cnr = border_set.right_btm_cnr.clone();
+        self.fill = border_set.fill.clone();
+        self
+    }
 
-            FrameVar::Round => Self {
-                left_top_cnr: '╭'.into(),
-                hor_top_line: '─'.into(),
-                right_top_cnr: '╮'.into(),
-                vert_left_line: '│'.into(),
-                vert_right_line: '│'.into(),
-                left_btm_cnr: '╰'.into(),
-                hor_btm_line: '─'.into(),
-                right_btm_cnr: '╯'.into(),
-                ..self
-            },
+    /// Border pieces and fill of the frame.
+    pub fn border_set(&self) -> BorderSet {
+        BorderSet {
+            left_top_cnr: self.left_top_cnr.clone(),
+            hor_top_line: self.hor_top_line.clone(),
+            right_top_cnr: self.right_top_cnr.clone(),
+            vert_left_line: self.vert_left_line.clone(),
+            vert_right_line: self.vert_right_line.clone(),
+            left_btm_cnr: self.left_btm_cnr.clone(),
+            hor_btm_line: self.hor_btm_line.clone(),
+            right_btm_cnr: self.right_btm_cnr.clone(),
+            fill: self.fill.clone(),
         }
     }
 
     /// Create an iterator frame with a newline.
-    #[cfg(feature = "color")]
     #[cfg(feature = "newline")]
     #[cfg_attr(docsrs, doc(cfg(feature = "newline")))]
-    pub fn frame_iterln<'a>(&'a self, text: &'a str) -> impl Iterator<Item = &str> + 'a {
-        self.frame_iter(&text).chain(iter::once("\n"))
+    pub fn frame_iterln<'a>(&'a self, text: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.frame_iter(text).chain(iter::once("\n"))
     }
 
     /// Create an iterator frame.
-    #[cfg(feature = "color")]
-    pub fn frame_iter<'a>(&'a self, text: &'a str) -> impl Iterator<Item = &str> {
-        let (lines, max_line_len) = max_line_len(text);
-
-        let sum_exp_width = self.expand_width + self.expand;
-        let max_line_len = if ((max_line_len + 2) + sum_exp_width * 2) < self.width {
-            (self.width - (max_line_len + 2 + sum_exp_width * 2)) + max_line_len
-        } else {
-            max_line_len
-        } + sum_exp_width * 2;
-
-        let sum_exp_height = self.expand + self.expand_height;
-        let sum_lines = sum_exp_height * 2 + 2 + lines;
-        let take_enlarge_top = (max_line_len + 7) * sum_exp_height;
-
-        let take_enlarge_btm = if sum_lines < self.height {
-            take_enlarge_top + (max_line_len + 7) * (self.height - sum_lines)
-        } else {
-            take_enlarge_top
-        };
-
-        let enlarge_line_iter = iter::once(self.color_fra.into_fg_str())
-            .chain(iter::once(self.vert_left_line.as_str()))
-            .chain(iter::once(self.color_fill.into_fg_str()))
-            .chain(iter::repeat(self.fill.as_str()).take(max_line_len))
-            .chain(iter::once(self.color_fra.into_fg_str()))
-            .chain(iter::once(self.vert_right_line.as_str()))
-            .chain(iter::once(Color::default().into_fg_str()))
-            .chain(iter::once("\n"))
-            .cycle();
-
-        let top_half_frame_iter = iter::once(self.color_fra.into_fg_str())
-            .chain(iter::once(self.left_top_cnr.as_str()))
-            .chain(iter::repeat(self.hor_top_line.as_str()).take(max_line_len))
-            .chain(iter::once(self.right_top_cnr.as_str()))
-            .chain(iter::once(Color::default().into_fg_str()))
-            .chain(iter::once("\n"))
-            .chain(enlarge_line_iter.clone().take(take_enlarge_top));
-
-        let bottom_half_frame_iter = enlarge_line_iter
-            .take(take_enlarge_btm)
-            .chain(iter::once(self.color_fra.into_fg_str()))
-            .chain(iter::once(self.left_btm_cnr.as_str()))
-            .chain(iter::repeat(self.hor_btm_line.as_str()).take(max_line_len))
-            .chain(iter::once(self.right_btm_cnr.as_str()))
-            .chain(iter::once(Color::default().into_fg_str()));
-
-        let lines_buffer_iter = text.lines().flat_map(move |line| {
-            let curr_line_len = line.chars().count();
-            let max_line_diff = max_line_len - curr_line_len;
-
-            let alignment = match self.algn {
-                Algn::Left => (sum_exp_width, max_line_diff - sum_exp_width),
-                Algn::Centr => (max_line_diff / 2, max_line_diff - max_line_diff / 2),
-                Algn::Right => (max_line_diff - sum_exp_width, sum_exp_width),
-            };
-
-            let iter_top = iter::once(self.color_fra.into_fg_str())
-                .chain(iter::once(self.vert_left_line.as_str()))
-                .chain(iter::once(self.color_fill.into_fg_str()))
-                .chain(iter::repeat(self.fill.as_str()).take(alignment.0));
-
-            let iter_line = iter::once(self.color_txt.into_fg_str()).chain(iter::once(line));
-
-            let iter_bottom = iter::once(self.color_fill.into_fg_str())
-                .chain(iter::repeat(self.fill.as_str()))
-                .take(alignment.1 + 1)
-                .chain(iter::once(self.color_fra.into_fg_str()))
-                .chain(iter::once(self.vert_right_line.as_str()))
-                .chain(iter::once(Color::default().into_fg_str()))
-                .chain(iter::once("\n"));
+    pub fn frame_iter<'a>(&'a self, text: &'a str) -> FrameIter<'a> {
+        FrameIter::new(self, text, self.layout(text, str_width))
+    }
 
-            iter_top.chain(iter_line).chain(iter_bottom)
-        });
+    /// Create an iterator frame with the width and alignment in place of the frame settings.
+    pub(crate) fn frame_iter_sized<'a>(
+        &'a self,
+        text: &'a str,
+        width: Option<usize>,
+        algn: Option<Algn>,
+    ) -> FrameIter<'a> {
+        let width = width.unwrap_or(self.width);
+        let algn = algn.unwrap_or(self.algn);
+        FrameIter::new(self, text, self.layout_sized(text, str_width, width, algn))
+    }
 
-        top_half_frame_iter
-            .chain(lines_buffer_iter)
-            .chain(bottom_half_frame_iter)
+    /// Create an iterator over the frame rows, without newlines.
+    pub fn frame_lines<'a>(&'a self, text: &'a str) -> impl Iterator<Item = FrameLine> + 'a {
+        self.lines_iter(text, self.layout(text, str_width))
     }
 
-    /// Create an iterator frame with a newline.
+    /// Create an iterator over the frame rows, without newlines.
+    ///
+    /// Escape codes in the text are left out of the plain rows as well.
     #[cfg(feature = "esc")]
     #[cfg(feature = "color")]
-    #[cfg(feature = "newline")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "newline")))]
-    pub fn frame_iterln_esc<'a>(&'a self, text: &'a str) -> impl Iterator<Item = &str> + 'a {
-        self.frame_iter(&text).chain(iter::once("\n"))
+    pub fn frame_lines_esc<'a>(&'a self, text: &'a str) -> impl Iterator<Item = FrameLine> + 'a {
+        self.lines_iter(text, self.layout(text, str_width_no_esc))
+            .map(FrameLine::strip_plain)
     }
 
-    /// Create an iterator frame.
+    /// Size of the frame around the text, without rendering it.
+    pub fn measure(&self, text: &str) -> FrameSize {
+        self.size(&self.layout(text, str_width))
+    }
+
+    /// Size of the frame around the text, without rendering it.
     #[cfg(feature = "esc")]
     #[cfg(feature = "color")]
-    pub fn frame_iter_esc<'a>(&'a self, text: &'a str) -> impl Iterator<Item = &str> + 'a {
-        let (lines, max_line_len) = max_line_len_no_esc(text);
-
-        let sum_exp_width = self.expand_width + self.expand;
-        let max_line_len = if ((max_line_len + 2) + sum_exp_width * 2) < self.width {
-            (self.width - (max_line_len + 2 + sum_exp_width * 2)) + max_line_len
-        } else {
-            max_line_len
-        } + sum_exp_width * 2;
-
-        let sum_exp_height = self.expand + self.expand_height;
-        let sum_lines = sum_exp_height * 2 + 2 + lines;
-        let take_enlarge_top = (max_line_len + 7) * sum_exp_height;
+    pub fn measure_esc(&self, text: &str) -> FrameSize {
+        self.size(&self.layout(text, str_width_no_esc))
+    }
 
-        let take_enlarge_btm = if sum_lines < self.height {
-            take_enlarge_top + (max_line_len + 7) * (self.height - sum_lines)
-        } else {
-            take_enlarge_top
-        };
+    /// Frame the lines of the reader one row at a time, in a frame of fixed width.
+    ///
+    /// Lines wider than the frame are wrapped or truncated. The height setting is ignored.
+    pub fn stream_reader<R: BufRead>(
+        &self,
+        width: usize,
+        overflow: Overflow,
+        reader: R,
+    ) -> FrameStream<io::Lines<R>> {
+        FrameStream::new(
+            self.clone().width(width).height(0),
+            overflow,
+            reader.lines(),
+        )
+    }
 
-        let enlarge_line_iter = iter::once(self.color_fra.into_fg_str())
-            .chain(iter::once(self.vert_left_line.as_str()))
-            .chain(iter::once(self.color_fill.into_fg_str()))
-            .chain(iter::repeat(self.fill.as_str()).take(max_line_len))
-            .chain(iter::once(self.color_fra.into_fg_str()))
-            .chain(iter::once(self.vert_right_line.as_str()))
-            .chain(iter::once(Color::default().into_fg_str()))
-            .chain(iter::once("\n"))
-            .cycle();
-
-        let top_half_frame_iter = iter::once(self.color_fra.into_fg_str())
-            .chain(iter::once(self.left_top_cnr.as_str()))
-            .chain(iter::repeat(self.hor_top_line.as_str()).take(max_line_len))
-            .chain(iter::once(self.right_top_cnr.as_str()))
-            .chain(iter::once(Color::default().into_fg_str()))
-            .chain(iter::once("\n"))
-            .chain(enlarge_line_iter.clone().take(take_enlarge_top));
-
-        let bottom_half_frame_iter = enlarge_line_iter
-            .take(take_enlarge_btm)
-            .chain(iter::once(self.color_fra.into_fg_str()))
-            .chain(iter::once(self.left_btm_cnr.as_str()))
-            .chain(iter::repeat(self.hor_btm_line.as_str()).take(max_line_len))
-            .chain(iter::once(self.right_btm_cnr.as_str()))
-            .chain(iter::once(Color::default().into_fg_str()));
-
-        let lines_buffer_iter = text.lines().flat_map(move |line| {
-            let curr_line_len = std::str::from_utf8(&strip(line).unwrap())
-                .unwrap()
-                .chars()
-                .count();
-            let max_line_diff = max_line_len - curr_line_len;
-
-            let alignment = match self.algn {
-                Algn::Left => (sum_exp_width, max_line_diff - sum_exp_width),
-                Algn::Centr => (max_line_diff / 2, max_line_diff - max_line_diff / 2),
-                Algn::Right => (max_line_diff - sum_exp_width, sum_exp_width),
-            };
+    /// Frame the lines one row at a time, in a frame of fixed width.
+    ///
+    /// Lines wider than the frame are wrapped or truncated. The height setting is ignored.
+    pub fn stream_lines<I: IntoIterator<Item = String>>(
+        &self,
+        width: usize,
+        overflow: Overflow,
+        lines: I,
+    ) -> FrameStream<impl Iterator<Item = io::Result<String>>> {
+        self.stream_iter(width, overflow, lines.into_iter().map(Ok))
+    }
 
-            let iter_top = iter::once(self.color_fra.into_fg_str())
-                .chain(iter::once(self.vert_left_line.as_str()))
-                .chain(iter::once(self.color_fill.into_fg_str()))
-                .chain(iter::repeat(self.fill.as_str()).take(alignment.0));
+    /// Frame streamed lines from an iterator of lines or read results.
+    pub(crate) fn stream_iter<I: Iterator<Item = io::Result<String>>>(
+        &self,
+        width: usize,
+        overflow: Overflow,
+        lines: I,
+    ) -> FrameStream<I> {
+        FrameStream::new(self.clone().width(width).height(0), overflow, lines)
+    }
 
-            let iter_line = iter::once(self.color_txt.into_fg_str()).chain(iter::once(line));
+    /// Create a writer that frames everything written to it on flush or drop.
+    pub fn writer<W: io::Write>(&self, out: W) -> FrameWriter<W> {
+        FrameWriter::buffered(self.clone(), out)
+    }
 
-            let iter_bottom = iter::once(self.color_fill.into_fg_str())
-                .chain(iter::repeat(self.fill.as_str()))
-                .take(alignment.1 + 1)
-                .chain(iter::once(self.color_fra.into_fg_str()))
-                .chain(iter::once(self.vert_right_line.as_str()))
-                .chain(iter::once(Color::default().into_fg_str()))
-                .chain(iter::once("\n"));
+    /// Create a writer that frames each line as it is written, in a frame of fixed width.
+    pub fn line_writer<W: io::Write>(
+        &self,
+        width: usize,
+        overflow: Overflow,
+        out: W,
+    ) -> FrameWriter<W> {
+        FrameWriter::lines(self.clone(), width, overflow, out)
+    }
 
-            iter_top.chain(iter_line).chain(iter_bottom)
-        });
+    /// Layout of a frame without text.
+    pub(crate) fn empty_layout(&self) -> Layout {
+        self.layout("", str_width)
+    }
 
-        top_half_frame_iter
-            .chain(lines_buffer_iter)
-            .chain(bottom_half_frame_iter)
+    /// Prepare a copy of the frame with the width for rendering many texts.
+    ///
+    /// The top and bottom edges and the blank rows are rendered once.
+    pub fn prepare(&self, width: usize) -> PreparedFrame {
+        PreparedFrame::new(self.clone().width(width))
     }
 
-    /// Create an iterator frame.
-    #[cfg(not(feature = "color"))]
-    #[cfg(feature = "newline")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "newline")))]
-    pub fn frame_iterln<'a>(&'a self, text: &'a str) -> impl Iterator<Item = &str> + 'a {
-        self.frame_iter(&text).chain(iter::once("\n"))
+    /// Render the text in a copy of the frame, owning both.
+    pub fn framed(&self, text: impl Into<String>) -> FramedText {
+        FramedText::new(self.clone(), text.into())
     }
 
-    /// Create an iterator frame.
-    #[cfg(not(feature = "color"))]
-    pub fn frame_iter<'a>(&'a self, text: &'a str) -> impl Iterator<Item = &str> + 'a {
-        let sum_exp_width = self.expand_width + self.expand;
-        let (lines, max_line_len) = max_line_len(text);
-        let sum_exp_height = self.expand + self.expand_height;
-        let sum_lines = sum_exp_height * 2 + 2 + lines;
+    /// Exact length of the frame in bytes.
+    ///
+    /// Runs of repeated pieces are counted by their length times the count,
+    /// nothing is rendered or allocated.
+    pub fn byte_len(&self, text: &str) -> usize {
+        let mut count = ByteCount::default();
+        self.push_frame(text, &mut count);
+        count.0
+    }
 
-        let max_line_len = if ((max_line_len + 2) + sum_exp_width * 2) < self.width {
-            (self.width - (max_line_len + 2 + sum_exp_width * 2)) + max_line_len
-        } else {
-            max_line_len
-        } + sum_exp_width * 2;
+    /// Writes the frame, returning the number of bytes written.
+    ///
+    /// Pieces are batched in a buffer on the stack, so the writer sees a few large writes.
+    pub fn write_to(&self, text: &str, out: &mut impl io::Write) -> io::Result<usize> {
+        let mut sink = IoSink::new(out);
+        self.push_frame(text, &mut sink);
+        sink.finish()
+    }
 
-        let take_enlarge_top = (max_line_len + 3) * sum_exp_height;
-        let take_enlarge_btm = if sum_lines < self.height {
-            (max_line_len + 3) * (self.height - sum_lines)
-        } else {
-            take_enlarge_top
-        };
+    /// Writes the frame to a formatter or string.
+    ///
+    /// Pieces are batched in a buffer on the stack, as with [`TextFrame::write_to`].
+    pub fn write_to_fmt(&self, text: &str, out: &mut impl fmt::Write) -> fmt::Result {
+        let mut sink = FmtSink::new(out);
+        self.push_frame(text, &mut sink);
+        sink.finish()
+    }
 
-        let enlarge_line_iter = iter::once(self.vert_left_line.as_str())
-            .chain(iter::repeat(self.fill.as_str()).take(max_line_len))
-            .chain(iter::once(self.vert_right_line.as_str()))
-            .chain(iter::once("\n"))
-            .cycle();
-
-        let top_half_frame_iter = iter::once(self.left_top_cnr.as_str())
-            .chain(iter::repeat(self.hor_top_line.as_str()).take(max_line_len))
-            .chain(iter::once(self.right_top_cnr.as_str()))
-            .chain(iter::once("\n"))
-            .chain(enlarge_line_iter.clone().take(take_enlarge_top));
-
-        let bottom_half_frame_iter = enlarge_line_iter
-            .take(take_enlarge_btm)
-            .chain(iter::once(self.left_btm_cnr.as_str()))
-            .chain(iter::repeat(self.hor_btm_line.as_str()).take(max_line_len))
-            .chain(iter::once(self.right_btm_cnr.as_str()));
-
-        let lines_buffer_iter = text.lines().flat_map(move |line| {
-            let curr_line_len = line.chars().count();
-            let max_line_diff = max_line_len - curr_line_len;
-
-            let alignment = match self.algn {
-                Algn::Left => (sum_exp_width, max_line_diff - sum_exp_width),
-                Algn::Centr => (max_line_diff / 2, max_line_diff - max_line_diff / 2),
-                Algn::Right => (max_line_diff - sum_exp_width, sum_exp_width),
+    /// Pushes the rows of the frame, stopping early once the sink fails.
+    fn push_frame<'a>(&'a self, text: &'a str, pieces: &mut impl PieceSink<'a>) {
+        let layout = self.layout(text, str_width);
+        let mut lines = text.lines();
+        for idx in 0.. {
+            let Some(row) = self.row_at(&layout, idx, &mut lines) else {
+                break;
             };
+            if idx > 0 {
+                pieces.push("\n");
+            }
+            self.push_line(&layout, idx, row, pieces);
+            if pieces.failed() {
+                break;
+            }
+        }
+    }
 
-            let iter_top = iter::once(self.vert_left_line.as_str())
-                .chain(iter::repeat(self.fill.as_str()).take(alignment.0));
-
-            let iter_line = iter::once(line);
-
-            let iter_bottom = iter::repeat(self.fill.as_str())
-                .take(alignment.1)
-                .chain(iter::once(self.vert_right_line.as_str()))
-                .chain(iter::once("\n"));
+    /// Text in the frame, taking width and alignment from the format spec.
+    pub fn display<'a>(&'a self, text: &'a str) -> FrameDisplay<'a> {
+        FrameDisplay::new(self, text)
+    }
 
-            iter_top.chain(iter_line).chain(iter_bottom)
-        });
+    /// Create an iterator frame with a newline.
+    #[cfg(feature = "esc")]
+    #[cfg(feature = "color")]
+    #[cfg(feature = "newline")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "newline")))]
+    pub fn frame_iterln_esc<'a>(&'a self, text: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.frame_iter_esc(text).chain(iter::once("\n"))
+    }
 
-        top_half_frame_iter
-            .chain(lines_buffer_iter)
-            .chain(bottom_half_frame_iter)
+    /// Create an iterator frame.
+    #[cfg(feature = "esc")]
+    #[cfg(feature = "color")]
+    pub fn frame_iter_esc<'a>(&'a self, text: &'a str) -> FrameIter<'a> {
+        FrameIter::new(self, text, self.layout(text, str_width_no_esc))
     }
 
     /// Specifies the top left corner.
-    pub fn left_top(mut self, ch: char) -> Self {
-        self.left_top_cnr = ch.into();
+    pub fn left_top(mut self, piece: impl Into<Piece>) -> Self {
+        self.left_top_cnr = piece.into();
         self
     }
 
     /// Change top left corner.
     #[inline]
-    pub fn set_left_top(&mut self, ch: char) -> &mut Self {
-        self.left_top_cnr = ch.into();
+    pub fn set_left_top(&mut self, piece: impl Into<Piece>) -> &mut Self {
+        self.left_top_cnr = piece.into();
         self
     }
 
+    /// Top left corner.
+    #[inline]
+    pub fn get_left_top(&self) -> &str {
+        &self.left_top_cnr
+    }
+
     /// Specifies the top line.
-    pub fn top_line(mut self, ch: char) -> Self {
-        self.hor_top_line = ch.into();
+    pub fn top_line(mut self, piece: impl Into<Piece>) -> Self {
+        self.hor_top_line = piece.into();
         self
     }
 
     /// Change top line.
     #[inline]
-    pub fn set_top_line(&mut self, ch: char) -> &mut Self {
-        self.hor_top_line = ch.into();
+    pub fn set_top_line(&mut self, piece: impl Into<Piece>) -> &mut Self {
+        self.hor_top_line = piece.into();
         self
     }
 
+    /// Top line.
+    #[inline]
+    pub fn get_top_line(&self) -> &str {
+        &self.hor_top_line
+    }
+
     /// Specifies the top right corner.
-    pub fn right_top(mut self, ch: char) -> Self {
-        self.right_top_cnr = ch.into();
+    pub fn right_top(mut self, piece: impl Into<Piece>) -> Self {
+        self.right_top_cnr = piece.into();
         self
     }
 
     /// Change top right corner.
     #[inline]
-    pub fn set_right_top(&mut self, ch: char) -> &mut Self {
-        self.right_top_cnr = ch.into();
+    pub fn set_right_top(&mut self, piece: impl Into<Piece>) -> &mut Self {
+        self.right_top_cnr = piece.into();
         self
     }
 
+    /// Top right corner.
+    #[inline]
+    pub fn get_right_top(&self) -> &str {
+        &self.right_top_cnr
+    }
+
     /// Specifies the left vertical line.
-    pub fn vert_left(mut self, ch: char) -> Self {
-        self.vert_left_line = ch.into();
+    pub fn vert_left(mut self, piece: impl Into<Piece>) -> Self {
+        self.vert_left_line = piece.into();
         self
     }
 
     /// Change left vertical line.
     #[inline]
-    pub fn set_vert_left(&mut self, ch: char) -> &mut Self {
-        self.vert_left_line = ch.into();
+    pub fn set_vert_left(&mut self, piece: impl Into<Piece>) -> &mut Self {
+        self.vert_left_line = piece.into();
         self
     }
 
+    /// Left vertical line.
+    #[inline]
+    pub fn get_vert_left(&self) -> &str {
+        &self.vert_left_line
+    }
+
     /// Specifies the right vertical line.
-    pub fn vert_right(mut self, ch: char) -> Self {
-        self.vert_right_line = ch.into();
+    pub fn vert_right(mut self, piece: impl Into<Piece>) -> Self {
+        self.vert_right_line = piece.into();
         self
     }
 
     /// Change right vertical line.
     #[inline]
-    pub fn set_vert_right(&mut self, ch: char) -> &mut Self {
-        self.vert_right_line = ch.into();
+    pub fn set_vert_right(&mut self, piece: impl Into<Piece>) -> &mut Self {
+        self.vert_right_line = piece.into();
         self
     }
 
+    /// Right vertical line.
+    #[inline]
+    pub fn get_vert_right(&self) -> &str {
+        &self.vert_right_line
+    }
+
     /// Specifies the bottom left corner.
-    pub fn left_btm(mut self, ch: char) -> Self {
-        self.left_btm_cnr = ch.into();
+    pub fn left_btm(mut self, piece: impl Into<Piece>) -> Self {
+        self.left_btm_cnr = piece.into();
         self
     }
 
     /// Change bottom left corner.
     #[inline]
-    pub fn set_left_btm(&mut self, ch: char) -> &mut Self {
-        self.left_btm_cnr = ch.into();
+    pub fn set_left_btm(&mut self, piece: impl Into<Piece>) -> &mut Self {
+        self.left_btm_cnr = piece.into();
         self
     }
 
+    /// Bottom left corner.
+    #[inline]
+    pub fn get_left_btm(&self) -> &str {
+        &self.left_btm_cnr
+    }
+
     /// Specifies the bottom line.
-    pub fn btm_line(mut self, ch: char) -> Self {
-        self.hor_btm_line = ch.into();
+    pub fn btm_line(mut self, piece: impl Into<Piece>) -> Self {
+        self.hor_btm_line = piece.into();
         self
     }
 
     /// Change bottom line.
     #[inline]
-    pub fn set_btm_line(&mut self, ch: char) -> &mut Self {
-        self.hor_btm_line = ch.into();
+    pub fn set_btm_line(&mut self, piece: impl Into<Piece>) -> &mut Self {
+        self.hor_btm_line = piece.into();
         self
     }
 
+    /// Bottom line.
+    #[inline]
+    pub fn get_btm_line(&self) -> &str {
+        &self.hor_btm_line
+    }
+
     /// Specifies the bottom right corner.
-    pub fn right_btm(mut self, ch: char) -> Self {
-        self.right_btm_cnr = ch.into();
+    pub fn right_btm(mut self, piece: impl Into<Piece>) -> Self {
+        self.right_btm_cnr = piece.into();
         self
     }
 
     /// Change bottom right corner
     #[inline]
-    pub fn set_right_btm(&mut self, ch: char) -> &mut Self {
-        self.right_btm_cnr = ch.into();
+    pub fn set_right_btm(&mut self, piece: impl Into<Piece>) -> &mut Self {
+        self.right_btm_cnr = piece.into();
         self
     }
 
+    /// Bottom right corner.
+    #[inline]
+    pub fn get_right_btm(&self) -> &str {
+        &self.right_btm_cnr
+    }
+
     /// Value for frame width.
     pub fn width(mut self, width: usize) -> Self {
         self.width = width;
@@ -589,6 +657,12 @@ impl TextFrame {
         self
     }
 
+    /// Frame width.
+    #[inline]
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
     /// Value for frame height.
     pub fn height(mut self, height: usize) -> Self {
         self.height = height;
@@ -602,6 +676,12 @@ impl TextFrame {
         self
     }
 
+    /// Frame height.
+    #[inline]
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
     /// Value for the width of the frame extension.
     pub fn expand_width(mut self, width: usize) -> Self {
         self.expand_width = width;
@@ -615,6 +695,12 @@ impl TextFrame {
         self
     }
 
+    /// Width of the frame extension.
+    #[inline]
+    pub fn get_expand_width(&self) -> usize {
+        self.expand_width
+    }
+
     /// Value for the height of the frame extension.
     pub fn expand_height(mut self, height: usize) -> Self {
         self.expand_height = height;
@@ -628,6 +714,12 @@ impl TextFrame {
         self
     }
 
+    /// Height of the frame extension.
+    #[inline]
+    pub fn get_expand_height(&self) -> usize {
+        self.expand_height
+    }
+
     /// Value for frame expand.
     pub fn expand(mut self, expand: usize) -> Self {
         self.expand = expand;
@@ -641,6 +733,12 @@ impl TextFrame {
         self
     }
 
+    /// Frame expand.
+    #[inline]
+    pub fn get_expand(&self) -> usize {
+        self.expand
+    }
+
     /// Specifies the text alignment.
     pub fn algn(mut self, algn: Algn) -> Self {
         self.algn = algn;
@@ -654,19 +752,140 @@ impl TextFrame {
         self
     }
 
+    /// Text alignment.
+    #[inline]
+    pub fn get_algn(&self) -> Algn {
+        self.algn
+    }
+
+    /// Specifies the visible sides.
+    pub fn sides(mut self, sides: Sides) -> Self {
+        self.sides = sides;
+        self
+    }
+
+    /// Change visible sides.
+    #[inline]
+    pub fn set_sides(&mut self, sides: Sides) -> &mut Self {
+        self.sides = sides;
+        self
+    }
+
+    /// Visible sides.
+    #[inline]
+    pub fn get_sides(&self) -> Sides {
+        self.sides
+    }
+
+    /// Adds a ring inside the innermost border.
+    pub fn ring(mut self, ring: Ring) -> Self {
+        self.set_ring(ring);
+        self
+    }
+
+    /// Add a ring inside the innermost border.
+    #[inline]
+    pub fn set_ring(&mut self, ring: Ring) -> &mut Self {
+        self.rings.push(ring);
+        self
+    }
+
+    /// Remove all rings.
+    #[inline]
+    pub fn clear_rings(&mut self) -> &mut Self {
+        self.rings.clear();
+        self
+    }
+
+    /// Rings from the outside in.
+    #[inline]
+    pub fn get_rings(&self) -> &[Ring] {
+        &self.rings
+    }
+
+    /// Specifies a drop shadow.
+    pub fn shadow(mut self, shadow: Shadow) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
+    /// Change drop shadow.
+    #[inline]
+    pub fn set_shadow(&mut self, shadow: Shadow) -> &mut Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
+    /// Remove the drop shadow.
+    #[inline]
+    pub fn clear_shadow(&mut self) -> &mut Self {
+        self.shadow = None;
+        self
+    }
+
+    /// Drop shadow.
+    #[inline]
+    pub fn get_shadow(&self) -> Option<&Shadow> {
+        self.shadow.as_ref()
+    }
+
     /// Specifies the fill character.
-    pub fn fill(mut self, fill: char) -> Self {
+    pub fn fill(mut self, fill: impl Into<Piece>) -> Self {
         self.fill = fill.into();
         self
     }
 
     /// Change fill character.
     #[inline]
-    pub fn set_fill(&mut self, fill: char) -> &mut Self {
+    pub fn set_fill(&mut self, fill: impl Into<Piece>) -> &mut Self {
         self.fill = fill.into();
         self
     }
 
+    /// Fill character.
+    #[inline]
+    pub fn get_fill(&self) -> &str {
+        &self.fill
+    }
+
+    /// Specifies how the fill graphemes are laid out.
+    pub fn fill_pattern(mut self, fill_pattern: FillPattern) -> Self {
+        self.fill_pattern = fill_pattern;
+        self
+    }
+
+    /// Change fill layout.
+    #[inline]
+    pub fn set_fill_pattern(&mut self, fill_pattern: FillPattern) -> &mut Self {
+        self.fill_pattern = fill_pattern;
+        self
+    }
+
+    /// Fill layout.
+    #[inline]
+    pub fn get_fill_pattern(&self) -> FillPattern {
+        self.fill_pattern
+    }
+
+    /// Specifies how the border lines repeat their pieces.
+    pub fn line_pattern(mut self, line_pattern: LinePattern) -> Self {
+        self.line_pattern = line_pattern;
+        self
+    }
+
+    /// Change border line layout.
+    #[inline]
+    pub fn set_line_pattern(&mut self, line_pattern: LinePattern) -> &mut Self {
+        self.line_pattern = line_pattern;
+        self
+    }
+
+    /// Border line layout.
+    #[inline]
+    pub fn get_line_pattern(&self) -> LinePattern {
+        self.line_pattern
+    }
+
     /// Specifies the frame color.
     #[cfg(feature = "color")]
     #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
@@ -684,11 +903,19 @@ impl TextFrame {
         self
     }
 
+    /// Frame color.
+    #[inline]
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn get_color_fra(&self) -> Color {
+        self.color_fra
+    }
+
     /// Specifies the text color.
     #[cfg(feature = "color")]
     #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
     pub fn color_txt(mut self, color: Color) -> Self {
-        self.color_txt = color;
+        self.set_color_txt(color);
         self
     }
 
@@ -698,9 +925,21 @@ impl TextFrame {
     #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
     pub fn set_color_txt(&mut self, color: Color) -> &mut Self {
         self.color_txt = color;
+        self.color_txt_auto = false;
         self
     }
 
+    /// Text color, black or white against the background when automatic.
+    #[inline]
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn get_color_txt(&self) -> Color {
+        match self.color_txt_auto {
+            true => self.color_bg.readable(),
+            false => self.color_txt,
+        }
+    }
+
     /// Specifies the fill color.
     #[cfg(feature = "color")]
     #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
@@ -717,22 +956,1029 @@ impl TextFrame {
         self.color_fill = color;
         self
     }
+
+    /// Fill color.
+    #[inline]
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn get_color_fill(&self) -> Color {
+        self.color_fill
+    }
+
+    /// Specifies the background color inside the frame.
+    ///
+    /// With [`FrameVar::HalfBlock`] a background equal to the frame color
+    /// joins the half-height lines into a solid panel.
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn color_bg(mut self, color: Color) -> Self {
+        self.color_bg = color;
+        self
+    }
+
+    /// Change background color inside the frame.
+    #[inline]
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn set_color_bg(&mut self, color: Color) -> &mut Self {
+        self.color_bg = color;
+        self
+    }
+
+    /// Background color inside the frame.
+    #[inline]
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn get_color_bg(&self) -> Color {
+        self.color_bg
+    }
+
+    /// Specifies the text attributes.
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn attr_txt(mut self, attr: Attr) -> Self {
+        self.attr_txt = attr;
+        self
+    }
+
+    /// Change text attributes.
+    #[inline]
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn set_attr_txt(&mut self, attr: Attr) -> &mut Self {
+        self.attr_txt = attr;
+        self
+    }
+
+    /// Text attributes.
+    #[inline]
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn get_attr_txt(&self) -> Attr {
+        self.attr_txt
+    }
+
+    /// Selecting a theme.
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn theme(mut self, theme: &Theme) -> Self {
+        theme.apply(&mut self);
+        self
+    }
+
+    /// Change theme.
+    #[inline]
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn set_theme(&mut self, theme: &Theme) -> &mut Self {
+        theme.apply(self);
+        self
+    }
+
+    /// Specifies black or white text, whichever is more readable on the background.
+    ///
+    /// The color is picked when rendering, against the current [`color_bg`](Self::color_bg).
+    /// The text keeps the terminal default color on the default background.
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn color_txt_auto(mut self) -> Self {
+        self.set_color_txt_auto();
+        self
+    }
+
+    /// Change text color to black or white, whichever is more readable on the background.
+    #[inline]
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn set_color_txt_auto(&mut self) -> &mut Self {
+        self.color_txt_auto = true;
+        self
+    }
+
+    /// Whether the text color is picked against the background.
+    #[inline]
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn get_color_txt_auto(&self) -> bool {
+        self.color_txt_auto
+    }
+
+    /// Highlights every occurrence of the term with the color.
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn highlight(mut self, term: &str, color: Color) -> Self {
+        self.set_highlight(term, color);
+        self
+    }
+
+    /// Add a highlighted term.
+    #[inline]
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn set_highlight(&mut self, term: &str, color: Color) -> &mut Self {
+        self.highlights.push(Highlight {
+            term: term.into(),
+            color,
+        });
+        self
+    }
+
+    /// Remove all highlighted terms.
+    #[inline]
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn clear_highlights(&mut self) -> &mut Self {
+        self.highlights.clear();
+        self
+    }
+
+    /// Highlighted terms and their colors.
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn get_highlights(&self) -> impl Iterator<Item = (&str, Color)> {
+        self.highlights
+            .iter()
+            .map(|hl| (hl.term.as_str(), hl.color))
+    }
+
+    /// Specifies case-insensitive matching of highlighted terms.
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn ignore_case(mut self, ignore_case: bool) -> Self {
+        self.ignore_case = ignore_case;
+        self
+    }
+
+    /// Change case-insensitive matching of highlighted terms.
+    #[inline]
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn set_ignore_case(&mut self, ignore_case: bool) -> &mut Self {
+        self.ignore_case = ignore_case;
+        self
+    }
+
+    /// Case-insensitive matching of highlighted terms.
+    #[inline]
+    #[cfg(feature = "color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn get_ignore_case(&self) -> bool {
+        self.ignore_case
+    }
+}
+
+/// Rows that do not depend on the text, for a fixed frame width.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct RowCache {
+    frame_width: usize,
+    top: String,
+    btm: String,
+    blank: String,
+}
+
+/// Geometry of a frame around a text.
+#[derive(Clone, Debug)]
+pub(crate) struct Layout {
+    inner_width: usize,
+    sum_exp_width: usize,
+    top_line_width: usize,
+    btm_line_width: usize,
+    enlarge_top: usize,
+    middle_rows: usize,
+    lines: usize,
+    vert_left_width: usize,
+    vert_right_width: usize,
+    rings: Vec<RingLayout>,
+    ring_rows: usize,
+    frame_width: usize,
+    frame_rows: usize,
+    algn: Algn,
+    str_width: fn(&str) -> usize,
+}
+
+impl Layout {
+    /// Layout with more text lines, for streamed text.
+    pub(crate) fn add_lines(&self, lines: usize) -> Self {
+        let mut layout = self.clone();
+        layout.lines += lines;
+        layout.middle_rows += lines;
+        layout.frame_rows += lines;
+        for ring in &mut layout.rings {
+            ring.middle_rows += lines;
+        }
+        layout
+    }
+
+    /// Width available to a text line.
+    pub(crate) fn text_width(&self) -> usize {
+        self.inner_width.saturating_sub(self.sum_exp_width * 2)
+    }
+
+    pub(crate) fn str_width(&self) -> fn(&str) -> usize {
+        self.str_width
+    }
+}
+
+/// Geometry of a ring inside the frame.
+#[derive(Clone, Copy, Debug, Default)]
+struct RingLayout {
+    gap: usize,
+    outer_width: usize,
+    top_line_width: usize,
+    btm_line_width: usize,
+    vert_left_width: usize,
+    vert_right_width: usize,
+    top_row: usize,
+    middle_rows: usize,
+}
+
+/// A row of the frame.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Row<'a> {
+    Top,
+    Gap(usize, usize),
+    RingTop(usize, usize),
+    Enlarge(usize),
+    Line(usize, &'a str),
+    RingBtm(usize, usize),
+    Btm,
+    Shadow,
+}
+
+impl TextFrame {
+    fn layout(&self, text: &str, str_width: fn(&str) -> usize) -> Layout {
+        self.layout_sized(text, str_width, self.width, self.algn)
+    }
+
+    /// Layout with the width and alignment in place of the frame settings.
+    fn layout_sized(
+        &self,
+        text: &str,
+        str_width: fn(&str) -> usize,
+        width: usize,
+        algn: Algn,
+    ) -> Layout {
+        let side_width = |side, piece: &str| match self.sides.contains(side) {
+            true => str_width(piece),
+            false => 0,
+        };
+        let vert_width = |side, piece: &str| match self.sides.contains(side) {
+            true => self.line_width(piece, str_width),
+            false => 0,
+        };
+
+        let (lines, max_line_len) = max_line_len(text, str_width);
+        let vert_left_width = vert_width(Sides::LEFT, &self.vert_left_line);
+        let vert_right_width = vert_width(Sides::RIGHT, &self.vert_right_line);
+        let vert_width = vert_left_width + vert_right_width;
+
+        let mut rings = self
+            .rings
+            .iter()
+            .map(|ring| RingLayout {
+                gap: ring.gap,
+                vert_left_width: self.line_width(&ring.border.vert_left_line, str_width),
+                vert_right_width: self.line_width(&ring.border.vert_right_line, str_width),
+                ..RingLayout::default()
+            })
+            .collect::<Vec<_>>();
+        let ring_width =
+            |ring: &RingLayout| ring.gap * 2 + ring.vert_left_width + ring.vert_right_width;
+        let ring_corners = |ring: &Ring| {
+            let top = str_width(&ring.border.left_top_cnr) + str_width(&ring.border.right_top_cnr);
+            let btm = str_width(&ring.border.left_btm_cnr) + str_width(&ring.border.right_btm_cnr);
+            (top, btm)
+        };
+
+        let sum_exp_width = self.expand_width + self.expand;
+        let sum_ring_width = rings.iter().map(ring_width).sum::<usize>();
+        let content_width = (max_line_len + sum_exp_width * 2)
+            .max(width.saturating_sub(vert_width + sum_ring_width));
+        let inner_width = self.rings.iter().zip(&rings).rev().fold(
+            content_width,
+            |inner, (ring, ring_layout)| {
+                let (top_width, btm_width) = ring_corners(ring);
+                (inner + ring_layout.vert_left_width + ring_layout.vert_right_width)
+                    .max(top_width)
+                    .max(btm_width)
+                    + ring_layout.gap * 2
+            },
+        );
+        let top_width = side_width(Sides::LEFT, &self.left_top_cnr)
+            + side_width(Sides::RIGHT, &self.right_top_cnr);
+        let btm_width = side_width(Sides::LEFT, &self.left_btm_cnr)
+            + side_width(Sides::RIGHT, &self.right_btm_cnr);
+        let frame_width = (inner_width + vert_width).max(top_width).max(btm_width);
+
+        let mut outer_width = frame_width - vert_width;
+        let mut ring_rows = 0;
+        for (ring, ring_layout) in self.rings.iter().zip(&mut rings) {
+            let (top_width, btm_width) = ring_corners(ring);
+            let width = outer_width - ring_layout.gap * 2;
+            ring_layout.outer_width = outer_width;
+            ring_layout.top_line_width = width - top_width;
+            ring_layout.btm_line_width = width - btm_width;
+            ring_layout.top_row = ring_rows + ring_layout.gap;
+            outer_width = width - ring_layout.vert_left_width - ring_layout.vert_right_width;
+            ring_rows += ring_layout.gap + 1;
+        }
+
+        let sum_exp_height = self.expand + self.expand_height;
+        let edges = [Sides::TOP, Sides::BTM]
+            .into_iter()
+            .filter(|side| self.sides.contains(*side))
+            .count();
+        let sum_lines = sum_exp_height * 2 + edges + ring_rows * 2 + lines;
+        let enlarge_btm = sum_exp_height + self.height.saturating_sub(sum_lines);
+        let middle_rows = ring_rows * 2 + sum_exp_height + lines + enlarge_btm;
+
+        for ring_layout in &mut rings {
+            ring_layout.middle_rows = middle_rows - (ring_layout.top_row + 1) * 2;
+        }
+
+        Layout {
+            inner_width: outer_width,
+            sum_exp_width,
+            top_line_width: frame_width - top_width,
+            btm_line_width: frame_width - btm_width,
+            enlarge_top: sum_exp_height,
+            middle_rows,
+            lines,
+            vert_left_width,
+            vert_right_width,
+            rings,
+            ring_rows,
+            frame_width,
+            frame_rows: middle_rows + edges,
+            algn,
+            str_width,
+        }
+    }
+
+    fn lines_iter<'a>(
+        &'a self,
+        text: &'a str,
+        layout: Layout,
+    ) -> impl Iterator<Item = FrameLine> + 'a {
+        let mut lines = text.lines();
+        let mut idx = 0;
+        iter::from_fn(move || {
+            let row = self.row_at(&layout, idx, &mut lines)?;
+            let mut pieces = Vec::new();
+            self.push_line(&layout, idx, row, &mut pieces);
+            idx += 1;
+            Some(FrameLine::new(&pieces, layout.str_width))
+        })
+    }
+
+    /// Top, bottom and blank rows, if every row of a kind looks the same.
+    ///
+    /// Rings, shadows, fill patterns and vertical patterns vary by row.
+    pub(crate) fn row_cache(&self) -> Option<RowCache> {
+        let single = |pattern: &str| pattern.graphemes(true).count() <= 1;
+        let uniform = self.rings.is_empty()
+            && self.shadow.is_none()
+            && self.fill_pattern == FillPattern::Plain
+            && (self.line_pattern == LinePattern::Plain
+                || single(&self.vert_left_line) && single(&self.vert_right_line));
+        if !uniform {
+            return None;
+        }
+
+        let layout = self.layout("", str_width);
+        let row = |row| {
+            let mut pieces = Vec::new();
+            self.push_row(&layout, row, &mut pieces);
+            pieces.concat()
+        };
+
+        Some(RowCache {
+            frame_width: layout.frame_width,
+            top: row(Row::Top),
+            btm: row(Row::Btm),
+            blank: row(Row::Enlarge(0)),
+        })
+    }
+
+    /// Renders the text reusing the cached rows.
+    ///
+    /// Returns `false` without writing if the text does not fit the cached width.
+    pub(crate) fn render_cached(&self, text: &str, cache: &RowCache, out: &mut String) -> bool {
+        let layout = self.layout(text, str_width);
+        if layout.frame_width != cache.frame_width {
+            return false;
+        }
+
+        let mut lines = text.lines();
+        let mut pieces = Vec::new();
+        for idx in 0.. {
+            let Some(row) = self.row_at(&layout, idx, &mut lines) else {
+                break;
+            };
+            if idx > 0 {
+                out.push('\n');
+            }
+            match row {
+                Row::Top => out.push_str(&cache.top),
+                Row::Btm => out.push_str(&cache.btm),
+                Row::Enlarge(_) => out.push_str(&cache.blank),
+                row => {
+                    pieces.clear();
+                    self.push_row(&layout, row, &mut pieces);
+                    pieces.iter().for_each(|piece| out.push_str(piece));
+                }
+            }
+        }
+        true
+    }
+
+    fn size(&self, layout: &Layout) -> FrameSize {
+        let offset = self
+            .shadow
+            .as_ref()
+            .map(|shadow| (shadow, shadow_offset(shadow, layout.str_width)));
+        let shadow_x = offset.map_or(0, |(shadow, offset)| match shadow.is_left() {
+            true => offset,
+            false => 0,
+        });
+        let shadow_y = offset.map_or(0, |(shadow, _)| usize::from(shadow.is_top()));
+
+        let ring_x = layout
+            .rings
+            .iter()
+            .map(|ring| ring.gap + ring.vert_left_width)
+            .sum::<usize>();
+        let left_x = match self.sides.contains(Sides::LEFT) {
+            true => layout.vert_left_width,
+            false => 0,
+        };
+
+        FrameSize {
+            width: layout.frame_width + offset.map_or(0, |(_, offset)| offset),
+            height: self.row_count(layout),
+            inner: FrameRect {
+                x: shadow_x + left_x + ring_x,
+                y: shadow_y + usize::from(self.sides.contains(Sides::TOP)) + layout.ring_rows,
+                width: layout.inner_width,
+                height: layout.middle_rows - layout.ring_rows * 2,
+            },
+        }
+    }
+
+    /// Number of rows, the shadow row included.
+    pub(crate) fn row_count(&self, layout: &Layout) -> usize {
+        layout.frame_rows + usize::from(self.shadow.is_some())
+    }
+
+    /// Row at the index, taking text lines in order from `lines`.
+    pub(crate) fn row_at<'a>(
+        &self,
+        layout: &Layout,
+        idx: usize,
+        lines: &mut impl Iterator<Item = &'a str>,
+    ) -> Option<Row<'a>> {
+        let shadow_top = self.shadow.as_ref().map(Shadow::is_top);
+        let edges = [
+            (shadow_top == Some(true), Row::Shadow),
+            (self.sides.contains(Sides::TOP), Row::Top),
+        ];
+        let mut idx = idx;
+        for (visible, row) in edges {
+            match (visible, idx) {
+                (false, _) => {}
+                (true, 0) => return Some(row),
+                (true, _) => idx -= 1,
+            }
+        }
+
+        if idx < layout.middle_rows {
+            return Some(self.middle_row_at(layout, idx, lines));
+        }
+        idx -= layout.middle_rows;
+
+        let edges = [
+            (self.sides.contains(Sides::BTM), Row::Btm),
+            (shadow_top == Some(false), Row::Shadow),
+        ];
+        for (visible, row) in edges {
+            match (visible, idx) {
+                (false, _) => {}
+                (true, 0) => return Some(row),
+                (true, _) => idx -= 1,
+            }
+        }
+        None
+    }
+
+    fn middle_row_at<'a>(
+        &self,
+        layout: &Layout,
+        row: usize,
+        lines: &mut impl Iterator<Item = &'a str>,
+    ) -> Row<'a> {
+        let lines_top = layout.ring_rows + layout.enlarge_top;
+        let content_btm = layout.middle_rows - layout.ring_rows;
+
+        if row < layout.ring_rows {
+            ring_row_at(layout, row, row, Row::RingTop)
+        } else if row >= content_btm {
+            ring_row_at(layout, row, layout.middle_rows - 1 - row, Row::RingBtm)
+        } else if (lines_top..lines_top + layout.lines).contains(&row) {
+            lines
+                .next()
+                .map_or(Row::Enlarge(row), |line| Row::Line(row, line))
+        } else {
+            Row::Enlarge(row)
+        }
+    }
+
+    /// Row at the index, with the shadow beside it.
+    pub(crate) fn push_line<'a>(
+        &'a self,
+        layout: &Layout,
+        idx: usize,
+        row: Row<'a>,
+        pieces: &mut impl PieceSink<'a>,
+    ) {
+        match &self.shadow {
+            Some(shadow) => self.push_shadowed(layout, shadow, idx, row, pieces),
+            None => self.push_row(layout, row, pieces),
+        }
+    }
+
+    fn push_row<'a>(&'a self, layout: &Layout, row: Row<'a>, pieces: &mut impl PieceSink<'a>) {
+        let left = self.sides.contains(Sides::LEFT);
+        let right = self.sides.contains(Sides::RIGHT);
+        let str_width = layout.str_width;
+
+        match row {
+            Row::Top => {
+                push_esc(pieces, self.esc_fra());
+                if left {
+                    pieces.push(&self.left_top_cnr);
+                }
+                self.push_hor_line(pieces, &self.hor_top_line, layout.top_line_width, str_width);
+                if right {
+                    pieces.push(&self.right_top_cnr);
+                }
+                push_esc(pieces, self.esc_reset());
+            }
+            Row::Gap(row, depth) => self.push_middle(layout, row, depth, pieces, |pieces, pos| {
+                self.push_fill(layout, pieces, pos, layout.rings[depth].outer_width);
+            }),
+            Row::RingTop(row, depth) => {
+                self.push_middle(layout, row, depth, pieces, |pieces, (x, y)| {
+                    let (ring, ring_layout) = (&self.rings[depth], &layout.rings[depth]);
+                    self.push_fill(layout, pieces, (x, y), ring_layout.gap);
+                    push_esc(pieces, self.esc_ring(ring));
+                    pieces.push(&ring.border.left_top_cnr);
+                    self.push_hor_line(
+                        pieces,
+                        &ring.border.hor_top_line,
+                        ring_layout.top_line_width,
+                        str_width,
+                    );
+                    pieces.push(&ring.border.right_top_cnr);
+                    self.push_esc_fill(pieces);
+                    let x = x + ring_layout.outer_width - ring_layout.gap;
+                    self.push_fill(layout, pieces, (x, y), ring_layout.gap);
+                })
+            }
+            Row::Enlarge(row) => {
+                self.push_middle(layout, row, self.rings.len(), pieces, |pieces, pos| {
+                    self.push_fill(layout, pieces, pos, layout.inner_width);
+                })
+            }
+            Row::Line(row, line) => {
+                let line_width = str_width(line);
+                let max_line_diff = layout.inner_width.saturating_sub(line_width);
+                let sum_exp_width = layout.sum_exp_width;
+
+                let alignment = match layout.algn {
+                    Algn::Left => (sum_exp_width, max_line_diff - sum_exp_width),
+                    Algn::Centr => (max_line_diff / 2, max_line_diff - max_line_diff / 2),
+                    Algn::Right => (max_line_diff - sum_exp_width, sum_exp_width),
+                };
+
+                self.push_middle(layout, row, self.rings.len(), pieces, |pieces, (x, y)| {
+                    self.push_fill(layout, pieces, (x, y), alignment.0);
+                    self.push_txt_line(line, pieces);
+                    self.push_esc_fill(pieces);
+                    let x = x + alignment.0 + line_width;
+                    self.push_fill(layout, pieces, (x, y), alignment.1);
+                });
+            }
+            Row::RingBtm(row, depth) => {
+                self.push_middle(layout, row, depth, pieces, |pieces, (x, y)| {
+                    let (ring, ring_layout) = (&self.rings[depth], &layout.rings[depth]);
+                    self.push_fill(layout, pieces, (x, y), ring_layout.gap);
+                    push_esc(pieces, self.esc_ring(ring));
+                    pieces.push(&ring.border.left_btm_cnr);
+                    self.push_hor_line(
+                        pieces,
+                        &ring.border.hor_btm_line,
+                        ring_layout.btm_line_width,
+                        str_width,
+                    );
+                    pieces.push(&ring.border.right_btm_cnr);
+                    self.push_esc_fill(pieces);
+                    let x = x + ring_layout.outer_width - ring_layout.gap;
+                    self.push_fill(layout, pieces, (x, y), ring_layout.gap);
+                })
+            }
+            Row::Shadow => {
+                let Some(shadow) = &self.shadow else {
+                    return;
+                };
+                if !shadow.is_left() {
+                    pieces.push_repeat(" ", shadow_offset(shadow, str_width));
+                }
+                self.push_shadow(pieces, shadow, layout.frame_width, str_width);
+            }
+            Row::Btm => {
+                push_esc(pieces, self.esc_fra());
+                if left {
+                    pieces.push(&self.left_btm_cnr);
+                }
+                self.push_hor_line(pieces, &self.hor_btm_line, layout.btm_line_width, str_width);
+                if right {
+                    pieces.push(&self.right_btm_cnr);
+                }
+                push_esc(pieces, self.esc_reset());
+            }
+        }
+    }
+
+    /// Row with the shadow beside it, or the shadow row itself.
+    fn push_shadowed<'a>(
+        &'a self,
+        layout: &Layout,
+        shadow: &'a Shadow,
+        idx: usize,
+        row: Row<'a>,
+        pieces: &mut impl PieceSink<'a>,
+    ) {
+        let offset = shadow_offset(shadow, layout.str_width);
+        if let Row::Shadow = row {
+            self.push_row(layout, row, pieces);
+            return;
+        }
+
+        let frame_row = idx - usize::from(shadow.is_top());
+        let cast = match shadow.is_top() {
+            true => frame_row + 1 < layout.frame_rows,
+            false => frame_row > 0,
+        };
+        if shadow.is_left() {
+            match cast {
+                true => self.push_shadow(pieces, shadow, offset, layout.str_width),
+                false => pieces.push_repeat(" ", offset),
+            }
+            self.push_row(layout, row, pieces);
+        } else {
+            self.push_row(layout, row, pieces);
+            if cast {
+                self.push_shadow(pieces, shadow, offset, layout.str_width);
+            }
+        }
+    }
+
+    /// Row between the frame sides, with the sides of the rings enclosing `depth`.
+    ///
+    /// The center is passed the frame coordinates it starts at.
+    fn push_middle<'a, S: PieceSink<'a>>(
+        &'a self,
+        layout: &Layout,
+        row: usize,
+        depth: usize,
+        pieces: &mut S,
+        center: impl FnOnce(&mut S, (usize, usize)),
+    ) {
+        let str_width = layout.str_width;
+        let rings = self.rings.iter().zip(&layout.rings).take(depth);
+        let y = row + usize::from(self.sides.contains(Sides::TOP));
+        let mut x = 0;
+
+        if self.sides.contains(Sides::LEFT) {
+            push_esc(pieces, self.esc_fra());
+            self.push_vert_left(layout, row, pieces);
+            x += layout.vert_left_width;
+        }
+        self.push_esc_fill(pieces);
+        for (ring, ring_layout) in rings.clone() {
+            self.push_fill(layout, pieces, (x, y), ring_layout.gap);
+            push_esc(pieces, self.esc_ring(ring));
+            self.push_vert_line(
+                pieces,
+                &ring.border.vert_left_line,
+                (row - ring_layout.top_row - 1, ring_layout.middle_rows),
+                ring_layout.vert_left_width,
+                str_width,
+            );
+            self.push_esc_fill(pieces);
+            x += ring_layout.gap + ring_layout.vert_left_width;
+        }
+        center(pieces, (x, y));
+        x += match layout.rings.get(depth) {
+            Some(ring_layout) => ring_layout.outer_width,
+            None => layout.inner_width,
+        };
+        for (ring, ring_layout) in rings.rev() {
+            push_esc(pieces, self.esc_ring(ring));
+            self.push_vert_line(
+                pieces,
+                &ring.border.vert_right_line,
+                (row - ring_layout.top_row - 1, ring_layout.middle_rows),
+                ring_layout.vert_right_width,
+                str_width,
+            );
+            self.push_esc_fill(pieces);
+            x += ring_layout.vert_right_width;
+            self.push_fill(layout, pieces, (x, y), ring_layout.gap);
+            x += ring_layout.gap;
+        }
+        if self.sides.contains(Sides::RIGHT) {
+            push_esc(pieces, self.esc_bg_end());
+            push_esc(pieces, self.esc_fra());
+            self.push_vert_right(layout, row, pieces);
+        }
+        push_esc(pieces, self.esc_reset());
+    }
+
+    /// Fill over the width, starting at the frame coordinates.
+    fn push_fill<'a>(
+        &'a self,
+        layout: &Layout,
+        pieces: &mut impl PieceSink<'a>,
+        pos: (usize, usize),
+        width: usize,
+    ) {
+        match self.fill_pattern.shift() {
+            Some(shift) => {
+                push_fill_pattern(pieces, &self.fill, pos, shift, width, layout.str_width)
+            }
+            None => push_repeat(pieces, &self.fill, width, layout.str_width),
+        }
+    }
+
+    /// Shadow glyphs over the width with their escape codes.
+    fn push_shadow<'a>(
+        &self,
+        pieces: &mut impl PieceSink<'a>,
+        shadow: &'a Shadow,
+        width: usize,
+        str_width: fn(&str) -> usize,
+    ) {
+        let mut styled = false;
+        for esc in shadow.esc_iter() {
+            pieces.push(esc);
+            styled = true;
+        }
+        push_repeat(pieces, &shadow.glyph, width, str_width);
+        if styled {
+            pieces.push(self.esc_reset());
+        }
+    }
+
+    fn push_vert_left<'a>(&'a self, layout: &Layout, row: usize, pieces: &mut impl PieceSink<'a>) {
+        self.push_vert_line(
+            pieces,
+            &self.vert_left_line,
+            (row, layout.middle_rows),
+            layout.vert_left_width,
+            layout.str_width,
+        );
+    }
+
+    fn push_vert_right<'a>(&'a self, layout: &Layout, row: usize, pieces: &mut impl PieceSink<'a>) {
+        self.push_vert_line(
+            pieces,
+            &self.vert_right_line,
+            (row, layout.middle_rows),
+            layout.vert_right_width,
+            layout.str_width,
+        );
+    }
+
+    /// Width of a vertical line piece.
+    fn line_width(&self, piece: &str, str_width: fn(&str) -> usize) -> usize {
+        match self.line_pattern {
+            LinePattern::Plain => str_width(piece),
+            LinePattern::Mirror => pattern_width(piece, str_width),
+        }
+    }
+
+    fn push_hor_line<'a>(
+        &self,
+        pieces: &mut impl PieceSink<'a>,
+        piece: &'a str,
+        width: usize,
+        str_width: fn(&str) -> usize,
+    ) {
+        match self.line_pattern {
+            LinePattern::Plain => push_repeat(pieces, piece, width, str_width),
+            LinePattern::Mirror => push_hor_pattern(pieces, piece, width, str_width),
+        }
+    }
+
+    fn push_vert_line<'a>(
+        &self,
+        pieces: &mut impl PieceSink<'a>,
+        piece: &'a str,
+        pos: (usize, usize),
+        width: usize,
+        str_width: fn(&str) -> usize,
+    ) {
+        match self.line_pattern {
+            LinePattern::Plain => pieces.push(piece),
+            LinePattern::Mirror => push_vert_pattern(pieces, piece, pos, width, str_width),
+        }
+    }
+
+    /// Text line with its style and highlights.
+    #[cfg(feature = "color")]
+    fn push_txt_line<'a>(&'a self, line: &'a str, pieces: &mut impl PieceSink<'a>) {
+        let style = iter::once(self.get_color_txt().into_fg_str())
+            .chain(Some(self.esc_bg()).filter(|esc| !esc.is_empty()))
+            .chain(self.attr_txt.into_esc_iter());
+        for esc in style.clone() {
+            pieces.push(esc);
+        }
+
+        highlight_line(line, &self.highlights, self.ignore_case, style, pieces);
+
+        if !self.attr_txt.is_empty() {
+            pieces.push(Color::default().into_fg_str());
+        }
+    }
+
+    #[cfg(not(feature = "color"))]
+    fn push_txt_line<'a>(&'a self, line: &'a str, pieces: &mut impl PieceSink<'a>) {
+        pieces.push(line);
+    }
+
+    #[cfg(feature = "color")]
+    fn esc_fra(&self) -> &'static str {
+        self.color_fra.into_fg_str()
+    }
+
+    #[cfg(feature = "color")]
+    fn esc_fill(&self) -> &'static str {
+        self.color_fill.into_fg_str()
+    }
+
+    /// Background escape, empty for the terminal default.
+    #[cfg(feature = "color")]
+    fn esc_bg(&self) -> &'static str {
+        match self.color_bg {
+            Color::Default => "",
+            color => color.into_bg_str(),
+        }
+    }
+
+    /// Ends the background before the right side.
+    #[cfg(feature = "color")]
+    fn esc_bg_end(&self) -> &'static str {
+        match self.color_bg {
+            Color::Default => "",
+            _ => Color::default().into_fg_str(),
+        }
+    }
+
+    /// Ring escape, the frame escape for the default color.
+    #[cfg(feature = "color")]
+    fn esc_ring(&self, ring: &Ring) -> &'static str {
+        match ring.color {
+            Color::Default => self.esc_fra(),
+            color => color.into_fg_str(),
+        }
+    }
+
+    #[cfg(feature = "color")]
+    fn esc_reset(&self) -> &'static str {
+        Color::default().into_fg_str()
+    }
+
+    #[cfg(not(feature = "color"))]
+    fn esc_fra(&self) -> &'static str {
+        ""
+    }
+
+    #[cfg(not(feature = "color"))]
+    fn esc_fill(&self) -> &'static str {
+        ""
+    }
+
+    #[cfg(not(feature = "color"))]
+    fn esc_reset(&self) -> &'static str {
+        ""
+    }
+
+    #[cfg(not(feature = "color"))]
+    fn esc_ring(&self, _ring: &Ring) -> &'static str {
+        ""
+    }
+
+    #[cfg(not(feature = "color"))]
+    fn esc_bg(&self) -> &'static str {
+        ""
+    }
+
+    #[cfg(not(feature = "color"))]
+    fn esc_bg_end(&self) -> &'static str {
+        ""
+    }
+
+    /// Fill color followed by the background.
+    fn push_esc_fill<'a>(&self, pieces: &mut impl PieceSink<'a>) {
+        push_esc(pieces, self.esc_fill());
+        push_esc(pieces, self.esc_bg());
+    }
+}
+
+#[inline]
+fn push_esc<'a>(pieces: &mut impl PieceSink<'a>, esc: &'a str) {
+    if !esc.is_empty() {
+        pieces.push(esc);
+    }
+}
+
+/// Gap or edge row of the ring `dist` rows from the frame edge.
+fn ring_row_at<'a>(
+    layout: &Layout,
+    row: usize,
+    dist: usize,
+    edge: fn(usize, usize) -> Row<'a>,
+) -> Row<'a> {
+    let depth = layout
+        .rings
+        .iter()
+        .position(|ring| dist <= ring.top_row)
+        .unwrap_or_default();
+    match layout.rings[depth].top_row == dist {
+        true => edge(row, depth),
+        false => Row::Gap(row, depth),
+    }
+}
+
+/// Width of the shadow cell, the offset from the frame.
+fn shadow_offset(shadow: &Shadow, str_width: fn(&str) -> usize) -> usize {
+    str_width(&shadow.glyph).max(1)
+}
+
+/// Repeats the piece over the width, ending with the graphemes of the piece that fit.
+///
+/// Columns left by a grapheme too wide to fit are padded with spaces.
+#[inline]
+fn push_repeat<'a>(
+    pieces: &mut impl PieceSink<'a>,
+    piece: &'a str,
+    width: usize,
+    str_width: fn(&str) -> usize,
+) {
+    let piece_width = str_width(piece);
+    let (count, rest) = match piece_width {
+        0 => (0, width),
+        _ => (width / piece_width, width % piece_width),
+    };
+
+    pieces.push_repeat(piece, count);
+
+    let mut end = 0;
+    let mut end_width = 0;
+    for (idx, grapheme) in piece.grapheme_indices(true) {
+        let grapheme_width = str_width(grapheme);
+        if end_width + grapheme_width > rest {
+            break;
+        }
+        end = idx + grapheme.len();
+        end_width += grapheme_width;
+    }
+    if end > 0 {
+        pieces.push(&piece[..end]);
+    }
+    pieces.push_repeat(" ", rest - end_width);
+}
+
+#[inline]
+fn str_width(text: &str) -> usize {
+    UnicodeWidthStr::width(text)
 }
 
 #[inline]
 #[cfg(feature = "esc")]
-fn max_line_len_no_esc(text: &str) -> (usize, usize) {
-    max_line_len(std::str::from_utf8(&strip(text).unwrap()).unwrap())
+fn str_width_no_esc(text: &str) -> usize {
+    str_width(std::str::from_utf8(&strip(text).unwrap()).unwrap())
 }
 
 #[inline]
-fn max_line_len(text: &str) -> (usize, usize) {
+fn max_line_len(text: &str, str_width: fn(&str) -> usize) -> (usize, usize) {
     let mut line_cout = 0;
     let max_len = text
         .lines()
         .map(|line| {
             line_cout += 1;
-            line.chars().count()
+            str_width(line)
         })
         .max()
         .unwrap_or(0);
@@ -998,3 +998,1292 @@ assert_eq!(&text_frame_main,
 "\u{1b}[0m✤――――――――――――――――――――――――――✤\u{1b}[0m\n\u{1b}[0m│\u{1b}[0m\u{1b}[0m\u{1b}[31m┌────────────────────────┐\u{1b}[0m\u{1b}[0m\u{1b}[0m│\u{1b}[0m\n\u{1b}[0m│\u{1b}[0m\u{1b}[0m\u{1b}[31m│\u{1b}[35m░░░░░░░░░░░░░░░░░░░░░░░░\u{1b}[31m│\u{1b}[0m\u{1b}[0m\u{1b}[0m│\u{1b}[0m\n\u{1b}[0m│\u{1b}[0m\u{1b}[0m\u{1b}[31m│\u{1b}[35m░░░░░░░░░░░░░░░░░░░░░░░░\u{1b}[31m│\u{1b}[0m\u{1b}[0m\u{1b}[0m│\u{1b}[0m\n\u{1b}[0m│\u{1b}[0m\u{1b}[0m\u{1b}[31m│\u{1b}[35m░░░░░░░\u{1b}[36mText frame\u{1b}[35m░░░░░░░\u{1b}[31m│\u{1b}[0m\u{1b}[0m\u{1b}[0m│\u{1b}[0m\n\u{1b}[0m│\u{1b}[0m\u{1b}[0m\u{1b}[31m│\u{1b}[35m░░░░░░░░░░░░░░\u{1b}[36mtxt\u{1b}[35m░░░░░░░\u{1b}[31m│\u{1b}[0m\u{1b}[0m\u{1b}[0m│\u{1b}[0m\n\u{1b}[0m│\u{1b}[0m\u{1b}[0m\u{1b}[31m│\u{1b}[35m░░░░░░░░░░░░░░░░░░░░░░░░\u{1b}[31m│\u{1b}[0m\u{1b}[0m\u{1b}[0m│\u{1b}[0m\n\u{1b}[0m│\u{1b}[0m\u{1b}[0m\u{1b}[31m│\u{1b}[35m░░░░░░░░░░░░░░░░░░░░░░░░\u{1b}[31m│\u{1b}[0m\u{1b}[0m\u{1b}[0m│\u{1b}[0m\n\u{1b}[0m│\u{1b}[0m\u{1b}[0m\u{1b}[31m└────────────────────────┘\u{1b}[0m\u{1b}[0m\u{1b}[0m│\u{1b}[0m\n\u{1b}[0m✤――――――――――――――――――――――――――✤\u{1b}[0m"
 );
 }
+
+#[cfg(feature = "color")]
+#[test]
+fn test_default_frame_highlight() {
+    let txtframe = TextFrame::new().highlight("fail", Color::Red);
+    let txtframe_iter = txtframe.frame_iter("test fail");
+
+    assert_eq!(
+    &txtframe_iter.collect::<String>(),
+    "\u{1b}[0m┌─────────┐\u{1b}[0m\n\u{1b}[0m│\u{1b}[0m\u{1b}[0mtest \u{1b}[31mfail\u{1b}[0m\u{1b}[0m\u{1b}[0m│\u{1b}[0m\n\u{1b}[0m└─────────┘\u{1b}[0m"
+);
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn test_default_frame_highlight_ignore_case() {
+    let txtframe = TextFrame::new()
+        .color_txt(Color::Cyan)
+        .highlight("ok", Color::Green)
+        .highlight("fail", Color::Red)
+        .ignore_case(true);
+    let txtframe_iter = txtframe.frame_iter("OK Fail");
+
+    assert_eq!(
+    &txtframe_iter.collect::<String>(),
+    "\u{1b}[0m┌───────┐\u{1b}[0m\n\u{1b}[0m│\u{1b}[0m\u{1b}[36m\u{1b}[32mOK\u{1b}[36m \u{1b}[31mFail\u{1b}[36m\u{1b}[0m\u{1b}[0m│\u{1b}[0m\n\u{1b}[0m└───────┘\u{1b}[0m"
+);
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn test_default_frame_highlight_width() {
+    let txtframe = TextFrame::new().algn(Algn::Centr).width(9);
+    let highlighted = txtframe
+        .clone()
+        .highlight("b", Color::Blue)
+        .frame_iter("abc")
+        .collect::<String>();
+    let plain = txtframe.frame_iter("abc").collect::<String>();
+
+    assert_eq!(
+        highlighted.replace("\u{1b}[34m", "").replace("b\u{1b}[0m", "b"),
+        plain
+    );
+}
+
+#[cfg(all(feature = "color", feature = "esc"))]
+#[test]
+fn test_default_frame_highlight_esc_in_text() {
+    let txtframe = TextFrame::new().highlight("1", Color::Red);
+    let row = txtframe
+        .frame_iter_esc("\u{1b}[1mab1")
+        .collect::<String>()
+        .lines()
+        .nth(1)
+        .unwrap()
+        .to_owned();
+
+    assert_eq!(
+        row,
+        "\u{1b}[0m│\u{1b}[0m\u{1b}[0m\u{1b}[1mab\u{1b}[31m1\u{1b}[0m\u{1b}[1m\u{1b}[0m\u{1b}[0m│\u{1b}[0m"
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn test_color_contrast_ratio() {
+    assert_eq!(Color::Black.contrast_ratio(Color::Black), Some(1.0));
+    assert_eq!(Color::Default.contrast_ratio(Color::White), None);
+    assert!(Color::White.contrast_ratio(Color::Black).unwrap() > 15.0);
+    assert_eq!(
+        Color::Red.contrast_ratio(Color::Cyan),
+        Color::Cyan.contrast_ratio(Color::Red)
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn test_color_readable() {
+    assert_eq!(Color::Blue.readable(), Color::White);
+    assert_eq!(Color::Yellow.readable(), Color::Black);
+    assert_eq!(Color::Default.readable(), Color::Default);
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn test_default_frame_color_txt_auto() {
+    let mut txtframe = TextFrame::new().color_bg(Color::Cyan).color_txt_auto();
+    let txtframe_iter = txtframe.frame_iter("Text");
+
+    assert_eq!(
+    &txtframe_iter.collect::<String>(),
+    "\u{1b}[0m┌────┐\u{1b}[0m\n\u{1b}[0m│\u{1b}[0m\u{1b}[46m\u{1b}[30m\u{1b}[46mText\u{1b}[0m\u{1b}[46m\u{1b}[0m\u{1b}[0m│\u{1b}[0m\n\u{1b}[0m└────┘\u{1b}[0m"
+);
+
+    txtframe.set_color_bg(Color::Blue);
+    assert_eq!(txtframe.get_color_txt(), Color::White);
+    txtframe.set_color_bg(Color::Default);
+    assert_eq!(txtframe.get_color_txt(), Color::Default);
+    txtframe.set_color_txt(Color::Red);
+    assert!(!txtframe.get_color_txt_auto());
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn test_default_frame_attr_txt() {
+    let txtframe = TextFrame::new()
+        .color_fill(Color::Green)
+        .attr_txt(Attr::BOLD | Attr::UNDERLINE);
+    let txtframe_iter = txtframe.frame_iter("Text");
+
+    assert_eq!(
+    &txtframe_iter.collect::<String>(),
+    "\u{1b}[0m┌────┐\u{1b}[0m\n\u{1b}[0m│\u{1b}[32m\u{1b}[0m\u{1b}[1m\u{1b}[4mText\u{1b}[0m\u{1b}[32m\u{1b}[0m│\u{1b}[0m\n\u{1b}[0m└────┘\u{1b}[0m"
+);
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn test_default_frame_theme_error() {
+    let txtframe = TextFrame::new().theme(&Theme::error());
+    let txtframe_iter = txtframe.frame_iter("Fail");
+
+    assert_eq!(
+    &txtframe_iter.collect::<String>(),
+    "\u{1b}[31m╔══════╗\u{1b}[0m\n\u{1b}[31m║\u{1b}[0m \u{1b}[31m\u{1b}[1mFail\u{1b}[0m\u{1b}[0m \u{1b}[31m║\u{1b}[0m\n\u{1b}[31m╚══════╝\u{1b}[0m"
+);
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn test_theme_named() {
+    assert_eq!(Theme::named("warning"), Some(Theme::warning()));
+    assert_eq!(Theme::named("unknown"), None);
+
+    let theme = Theme::new().frame_var(FrameVar::Space).color_fra(Color::Cyan);
+    assert_eq!(Theme::register("test_custom", theme), None);
+    assert_eq!(Theme::named("test_custom"), Some(theme));
+    assert_eq!(Theme::register("test_custom", Theme::muted()), Some(theme));
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn test_default_frame_set_theme() {
+    let mut txtframe = TextFrame::new();
+    txtframe.set_theme(&Theme::success());
+
+    assert_eq!(
+        txtframe,
+        TextFrame::new()
+            .frame_var(&FrameVar::Round)
+            .color_fra(Color::Green)
+            .color_txt(Color::Green)
+            .expand_width(1)
+    );
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "color")]
+#[test]
+fn serde_test_default_frame_json() {
+    let txtframe = TextFrame::new()
+        .frame_var(&FrameVar::Round)
+        .algn(Algn::Centr)
+        .color_fra(Color::Red)
+        .attr_txt(Attr::BOLD | Attr::ITALIC)
+        .highlight("fail", Color::Yellow)
+        .width(20);
+    let json = serde_json::to_string(&txtframe).unwrap();
+
+    assert_eq!(
+        json,
+        r#"{"left_top":"╭","top_line":"─","right_top":"╮","left_btm":"╰","vert_left":"│","vert_right":"│","btm_line":"─","right_btm":"╯","fill":" ","fill_pattern":"plain","line_pattern":"plain","width":20,"height":0,"expand":0,"expand_width":0,"expand_height":0,"algn":"centr","sides":["top","btm","left","right"],"rings":[],"shadow":null,"color_fra":"red","color_txt":"default","color_txt_auto":false,"color_fill":"default","color_bg":"default","attr_txt":["bold","italic"],"highlights":[{"term":"fail","color":"yellow"}],"ignore_case":false}"#
+    );
+    assert_eq!(serde_json::from_str::<TextFrame>(&json).unwrap(), txtframe);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_test_default_frame_toml() {
+    let txtframe: TextFrame = toml::from_str(
+        r#"
+        left_top = "✤"
+        fill = "░"
+        width = 12
+        algn = "center"
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        txtframe,
+        TextFrame::new()
+            .left_top('✤')
+            .fill('░')
+            .width(12)
+            .algn(Algn::Centr)
+    );
+    assert_eq!(
+        toml::from_str::<TextFrame>(&toml::to_string(&txtframe).unwrap()).unwrap(),
+        txtframe
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_test_invalid_values() {
+    let err = serde_json::from_str::<TextFrame>(r#"{"fill":""}"#).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid value: string \"\", expected a non-empty string at line 1 column 10"
+    );
+
+    let err = serde_json::from_str::<TextFrame>(r#"{"colour":"red"}"#).unwrap_err();
+    assert!(err.to_string().starts_with("unknown field `colour`"));
+
+    let err = serde_json::from_str::<FrameVar>(r#""dotted""#).unwrap_err();
+    assert!(err.to_string().starts_with("unknown variant `dotted`"));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_test_color_keys_in_every_build() {
+    let json = r#"{"width":4,"color_fra":"red","color_txt_auto":true,"attr_txt":["bold"],"highlights":[],"ignore_case":true,"rings":[{"gap":1,"color":"blue"}],"shadow":{"color":"red","color_bg":"black"}}"#;
+    let txtframe: TextFrame = serde_json::from_str(json).unwrap();
+    assert_eq!(txtframe.get_width(), 4);
+    assert_eq!(txtframe.get_rings()[0].get_gap(), 1);
+
+    #[cfg(feature = "color")]
+    assert_eq!(txtframe.get_color_fra(), Color::Red);
+    #[cfg(not(feature = "color"))]
+    assert!(!serde_json::to_string(&txtframe).unwrap().contains("color"));
+
+    let err = serde_json::from_str::<Shadow>(r#"{"colour":"red"}"#).unwrap_err();
+    assert!(err.to_string().starts_with("unknown field `colour`"));
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "color")]
+#[test]
+fn serde_test_theme() {
+    let theme: Theme = toml::from_str(
+        r#"
+        frame_var = "heavy"
+        color_fra = "yellow"
+        attr_txt = ["bold"]
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        theme,
+        Theme::new()
+            .frame_var(FrameVar::Heavy)
+            .color_fra(Color::Yellow)
+            .attr_txt(Attr::BOLD)
+    );
+    assert!(toml::from_str::<Theme>(r#"attr_txt = ["loud"]"#)
+        .unwrap_err()
+        .to_string()
+        .contains("unknown variant `loud`"));
+}
+
+#[test]
+fn test_default_frame_getters() {
+    let txtframe = TextFrame::new()
+        .frame_var(&FrameVar::Heavy)
+        .algn(Algn::Right)
+        .width(30)
+        .height(4)
+        .expand(1)
+        .expand_width(2)
+        .expand_height(3)
+        .fill('░');
+
+    assert_eq!(txtframe.get_left_top(), "┏");
+    assert_eq!(txtframe.get_top_line(), "━");
+    assert_eq!(txtframe.get_right_btm(), "┛");
+    assert_eq!(txtframe.get_fill(), "░");
+    assert_eq!(txtframe.get_algn(), Algn::Right);
+    assert_eq!(txtframe.get_width(), 30);
+    assert_eq!(txtframe.get_height(), 4);
+    assert_eq!(txtframe.get_expand(), 1);
+    assert_eq!(txtframe.get_expand_width(), 2);
+    assert_eq!(txtframe.get_expand_height(), 3);
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn test_default_frame_color_getters() {
+    let txtframe = TextFrame::new()
+        .color_fra(Color::Red)
+        .color_txt(Color::Cyan)
+        .color_fill(Color::Blue)
+        .attr_txt(Attr::DIM)
+        .highlight("ok", Color::Green)
+        .ignore_case(true);
+
+    assert_eq!(txtframe.get_color_fra(), Color::Red);
+    assert_eq!(txtframe.get_color_txt(), Color::Cyan);
+    assert_eq!(txtframe.get_color_fill(), Color::Blue);
+    assert_eq!(txtframe.get_attr_txt(), Attr::DIM);
+    assert_eq!(
+        txtframe.get_highlights().collect::<Vec<_>>(),
+        [("ok", Color::Green)]
+    );
+    assert!(txtframe.get_ignore_case());
+}
+
+#[test]
+fn test_default_frame_border_set() {
+    let border_set = TextFrame::new().frame_var(&FrameVar::Round).border_set();
+
+    assert_eq!(
+        [
+            border_set.get_left_top(),
+            border_set.get_top_line(),
+            border_set.get_right_top(),
+            border_set.get_vert_left(),
+            border_set.get_vert_right(),
+            border_set.get_left_btm(),
+            border_set.get_btm_line(),
+            border_set.get_right_btm(),
+            border_set.get_fill(),
+        ],
+        ["╭", "─", "╮", "│", "│", "╰", "─", "╯", " "]
+    );
+}
+
+#[test]
+fn test_border_set_from_str() {
+    let border_set: BorderSet = "╔═╗║░║╚═╝".parse().unwrap();
+
+    assert_eq!(border_set, BorderSet::from(FrameVar::Double).fill('░'));
+    assert_eq!(border_set.to_string(), "╔═╗║░║╚═╝");
+    assert_eq!(
+        "┌─┐│ │└─".parse::<BorderSet>().unwrap_err().to_string(),
+        "expected 9 border pieces, found 8"
+    );
+}
+
+#[test]
+fn test_border_set_round_trip() {
+    let border_sets = [
+        BorderSet::from(FrameVar::Round).fill('░'),
+        BorderSet::new().top_line("=-"),
+        BorderSet::new().top_line("=-").fill(""),
+        BorderSet::new().left_top("e\u{301}"),
+    ];
+
+    for border_set in border_sets {
+        assert_eq!(border_set.to_string().parse::<BorderSet>().unwrap(), border_set);
+    }
+    assert_eq!(
+        BorderSet::new().top_line("=-").to_string(),
+        "┌\n=-\n┐\n│\n \n│\n└\n─\n┘"
+    );
+}
+
+#[test]
+fn test_border_set_edit() {
+    let mut border_set = BorderSet::new().left_top('✤').right_btm('✤');
+    border_set.set_top_line('═').set_btm_line('═');
+
+    assert_eq!(border_set.to_string(), "✤═┐│ │└═✤");
+    assert_ne!(border_set, BorderSet::default());
+}
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn test_default_frame_border() {
+    let border_set: BorderSet = "+-+|.|+-+".parse().unwrap();
+    let txtframe = TextFrame::new().border(&border_set).expand(1);
+    let txtframe_iter = txtframe.frame_iter("");
+
+    assert_eq!(&txtframe_iter.collect::<String>(), "+--+\n|..|\n|..|\n+--+");
+    assert_eq!(txtframe.border_set(), border_set);
+}
+
+#[test]
+fn test_default_frame_var_keeps_fill() {
+    let txtframe = TextFrame::new().fill('░').frame_var(&FrameVar::Round);
+
+    assert_eq!(
+        txtframe.border_set(),
+        BorderSet::from(FrameVar::Round).fill('░')
+    );
+}
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn test_default_frame_multi_glyph_pieces() {
+    let txtframe = TextFrame::new()
+        .left_top("<=")
+        .top_line("=")
+        .right_top("=>")
+        .left_btm("<=")
+        .btm_line("=")
+        .right_btm("=>");
+    let txtframe_iter = txtframe.frame_iter("abcd");
+
+    assert_eq!(
+        &txtframe_iter.collect::<String>(),
+        "<====>\n│abcd│\n<====>"
+    );
+}
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn test_default_frame_wide_line() {
+    let txtframe = TextFrame::new().top_line("<>");
+    let txtframe_iter = txtframe.frame_iter("abc");
+
+    assert_eq!(&txtframe_iter.collect::<String>(), "┌<><┐\n│abc│\n└───┘");
+
+    let txtframe = TextFrame::new().top_line("a🔥");
+    let txtframe_iter = txtframe.frame_iter("abcde");
+
+    assert_eq!(&txtframe_iter.collect::<String>(), "┌a🔥a ┐\n│abcde│\n└─────┘");
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn test_default_frame_emoji_corner() {
+    let txtframe = TextFrame::new().left_top("🔥");
+    let txtframe_iter = txtframe.frame_iter("abc");
+
+    assert_eq!(
+    &txtframe_iter.collect::<String>(),
+    "\u{1b}[0m🔥──┐\u{1b}[0m\n\u{1b}[0m│\u{1b}[0m\u{1b}[0mabc\u{1b}[0m\u{1b}[0m│\u{1b}[0m\n\u{1b}[0m└───┘\u{1b}[0m"
+);
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn test_default_frame_zwj_corners() {
+    let txtframe = TextFrame::new()
+        .left_top("👩‍💻")
+        .right_top("❤️")
+        .expand_width(1);
+    let txtframe_iter = txtframe.frame_iter("");
+
+    assert_eq!(
+        &txtframe_iter.collect::<String>(),
+        "\u{1b}[0m👩‍💻❤️\u{1b}[0m\n\u{1b}[0m└──┘\u{1b}[0m"
+    );
+}
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn test_default_frame_hor_pattern() {
+    let txtframe = TextFrame::new()
+        .top_line("─·")
+        .btm_line("◆◇")
+        .line_pattern(LinePattern::Mirror);
+    let txtframe_iter = txtframe.frame_iter("abcde\nabcd");
+
+    assert_eq!(
+        &txtframe_iter.collect::<String>(),
+        "┌─·─·─┐\n│abcde│\n│abcd │\n└◆◇◆◇◆┘"
+    );
+}
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn test_default_frame_hor_pattern_even() {
+    let txtframe = TextFrame::new()
+        .top_line("=-")
+        .btm_line("=-")
+        .line_pattern(LinePattern::Mirror);
+    let txtframe_iter = txtframe.frame_iter("abcd");
+
+    assert_eq!(
+        &txtframe_iter.collect::<String>(),
+        "┌=--=┐\n│abcd│\n└=--=┘"
+    );
+}
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn test_default_frame_vert_pattern() {
+    let txtframe = TextFrame::new()
+        .vert_left("┃│")
+        .vert_right("┃│")
+        .line_pattern(LinePattern::Mirror)
+        .height(7);
+    let txtframe_iter = txtframe.frame_iter("ab");
+
+    assert_eq!(
+        &txtframe_iter.collect::<String>(),
+        "┌──┐\n┃ab┃\n│  │\n┃  ┃\n│  │\n┃  ┃\n└──┘"
+    );
+}
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn test_default_frame_multi_glyph_sides() {
+    let txtframe = TextFrame::new().vert_left("<=").vert_right("=>").height(4);
+    let txtframe_iter = txtframe.frame_iter("ab");
+
+    assert_eq!(
+        &txtframe_iter.collect::<String>(),
+        "┌────┐\n<=ab=>\n<=  =>\n└────┘"
+    );
+    assert!(txtframe.prepare(6).render("ab").starts_with("┌────┐\n<=ab=>"));
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn test_default_frame_hor_pattern_wide() {
+    let txtframe = TextFrame::new()
+        .top_line("🔥")
+        .line_pattern(LinePattern::Mirror)
+        .width(7);
+    let txtframe_iter = txtframe.frame_iter("");
+
+    assert_eq!(
+        &txtframe_iter.collect::<String>(),
+        "\u{1b}[0m┌🔥 🔥┐\u{1b}[0m\n\u{1b}[0m└─────┘\u{1b}[0m"
+    );
+}
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn test_default_frame_sides_top_btm() {
+    let txtframe = TextFrame::new().sides(Sides::TOP | Sides::BTM).width(6);
+    let txtframe_iter = txtframe.frame_iter("ab");
+
+    assert_eq!(&txtframe_iter.collect::<String>(), "──────\nab    \n──────");
+}
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn test_default_frame_sides_left_bar() {
+    let txtframe = TextFrame::new()
+        .sides(Sides::LEFT)
+        .vert_left('▌')
+        .expand_width(1);
+    let txtframe_iter = txtframe.frame_iter("quote\ntext");
+
+    assert_eq!(&txtframe_iter.collect::<String>(), "▌ quote \n▌ text  ");
+}
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn test_default_frame_sides_open_right() {
+    let txtframe = TextFrame::new()
+        .sides(Sides::ALL - Sides::RIGHT)
+        .algn(Algn::Right)
+        .height(4);
+    let txtframe_iter = txtframe.frame_iter("abc\na");
+
+    assert_eq!(
+        &txtframe_iter.collect::<String>(),
+        "┌───\n│abc\n│  a\n└───"
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn test_default_frame_sides_none() {
+    let txtframe = TextFrame::new().sides(Sides::NONE).color_fra(Color::Red);
+    let txtframe_iter = txtframe.frame_iter("ab");
+
+    assert_eq!(
+        &txtframe_iter.collect::<String>(),
+        "\u{1b}[0m\u{1b}[0mab\u{1b}[0m\u{1b}[0m"
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn test_default_frame_sides_height() {
+    let txtframe = TextFrame::new().sides(Sides::LEFT | Sides::RIGHT).height(3);
+    let txtframe_iter = txtframe.frame_iter("ab");
+
+    assert_eq!(txtframe_iter.collect::<String>().lines().count(), 3);
+}
+
+#[test]
+fn test_line_styles_corners() {
+    let txtframe = TextFrame::new().line_styles(
+        LineStyles::all(LineStyle::Light)
+            .top(LineStyle::Heavy)
+            .left(LineStyle::Double),
+    );
+
+    assert_eq!(txtframe.get_left_top(), "╓");
+    assert_eq!(txtframe.get_right_top(), "┑");
+    assert_eq!(txtframe.get_left_btm(), "╙");
+    assert_eq!(txtframe.get_right_btm(), "┘");
+    assert_eq!(txtframe.get_top_line(), "━");
+    assert_eq!(txtframe.get_vert_left(), "║");
+}
+
+#[test]
+fn test_line_styles_frame_var() {
+    let hor_heavy = LineStyles::all(LineStyle::Light)
+        .top(LineStyle::Heavy)
+        .btm(LineStyle::Heavy);
+    let vert_double = LineStyles::all(LineStyle::Light)
+        .left(LineStyle::Double)
+        .right(LineStyle::Double);
+
+    assert_eq!(BorderSet::from(hor_heavy), BorderSet::from(FrameVar::HorHeavy));
+    assert_eq!(BorderSet::from(vert_double), BorderSet::from(FrameVar::VertDouble));
+    assert_eq!(
+        BorderSet::from(LineStyles::all(LineStyle::Double)),
+        BorderSet::from(FrameVar::Double)
+    );
+}
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn test_line_styles_dashed_none() {
+    let txtframe = TextFrame::new().line_styles(
+        LineStyles::all(LineStyle::Dashed).right(LineStyle::None),
+    );
+    let txtframe_iter = txtframe.frame_iter("ab");
+
+    assert_eq!(&txtframe_iter.collect::<String>(), "┌┄┄\n┆ab\n└┄┄");
+}
+
+#[test]
+fn test_frame_var_id_roundtrip() {
+    for vars in FrameVar::ALL {
+        assert_eq!(vars.id().parse::<FrameVar>(), Ok(vars));
+        assert!(!vars.name().is_empty());
+    }
+    assert_eq!(FrameVar::HeavyDashed.id(), "heavy_dashed");
+    assert_eq!(FrameVar::Ascii.name(), "ASCII");
+    assert_eq!("dotted".parse::<FrameVar>(), Err(ParseFrameVarError));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_test_frame_var_id() {
+    for vars in FrameVar::ALL {
+        assert_eq!(serde_json::to_string(&vars).unwrap(), format!("\"{}\"", vars.id()));
+    }
+}
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn test_frame_var_ascii() {
+    let txtframe = TextFrame::new().frame_var(&FrameVar::Ascii);
+    let txtframe_iter = txtframe.frame_iter("ab");
+
+    assert_eq!(&txtframe_iter.collect::<String>(), "+--+\n|ab|\n+--+");
+}
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn test_frame_var_dashed() {
+    let txtframe = TextFrame::new().frame_var(&FrameVar::HeavyDashed);
+    let txtframe_iter = txtframe.frame_iter("ab");
+
+    assert_eq!(&txtframe_iter.collect::<String>(), "┏┅┅┓\n┇ab┇\n┗┅┅┛");
+}
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn test_frame_var_quadrant() {
+    let txtframe = TextFrame::new().frame_var(&FrameVar::Quadrant);
+    let txtframe_iter = txtframe.frame_iter("ab");
+
+    assert_eq!(&txtframe_iter.collect::<String>(), "▛▀▀▜\n▌ab▐\n▙▄▄▟");
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn test_frame_var_half_block_bg() {
+    let txtframe = TextFrame::new()
+        .frame_var(&FrameVar::HalfBlock)
+        .color_fra(Color::Blue)
+        .color_bg(Color::Blue)
+        .color_txt(Color::White);
+    let txtframe_iter = txtframe.frame_iter("ab");
+
+    assert_eq!(
+        &txtframe_iter.collect::<String>(),
+        "\u{1b}[34m▄▄▄▄\u{1b}[0m\n\u{1b}[34m█\u{1b}[0m\u{1b}[44m\u{1b}[37m\u{1b}[44mab\u{1b}[0m\u{1b}[44m\u{1b}[0m\u{1b}[34m█\u{1b}[0m\n\u{1b}[34m▀▀▀▀\u{1b}[0m"
+    );
+}
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn test_default_frame_rings() {
+    let txtframe = TextFrame::new()
+        .frame_var(&FrameVar::Double)
+        .ring(Ring::new(&FrameVar::Light.into()).gap(1))
+        .ring(Ring::new(&FrameVar::Ascii.into()));
+    let txtframe_iter = txtframe.frame_iter("ab");
+
+    assert_eq!(
+        &txtframe_iter.collect::<String>(),
+        "╔════════╗\n║        ║\n║ ┌────┐ ║\n║ │+--+│ ║\n║ │|ab|│ ║\n║ │+--+│ ║\n║ └────┘ ║\n║        ║\n╚════════╝"
+    );
+}
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn test_default_frame_rings_width_height() {
+    let txtframe = TextFrame::new()
+        .ring(Ring::new(&FrameVar::Heavy.into()).gap(1))
+        .width(12)
+        .height(9)
+        .algn(Algn::Centr);
+    let frame = txtframe.frame_iter("ab").collect::<String>();
+
+    assert!(frame.lines().all(|line| line.chars().count() == 12));
+    assert_eq!(frame.lines().count(), 9);
+    assert_eq!(frame.lines().nth(3), Some("│ ┃  ab  ┃ │"));
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn test_default_frame_ring_color() {
+    let txtframe = TextFrame::new()
+        .color_fra(Color::Blue)
+        .ring(Ring::new(&FrameVar::Round.into()).color(Color::Red));
+    let txtframe_iter = txtframe.frame_iter("a");
+
+    assert_eq!(
+        &txtframe_iter.collect::<String>(),
+        "\u{1b}[34m┌───┐\u{1b}[0m\n\u{1b}[34m│\u{1b}[0m\u{1b}[31m╭─╮\u{1b}[0m\u{1b}[34m│\u{1b}[0m\n\u{1b}[34m│\u{1b}[0m\u{1b}[31m│\u{1b}[0m\u{1b}[0ma\u{1b}[0m\u{1b}[31m│\u{1b}[0m\u{1b}[34m│\u{1b}[0m\n\u{1b}[34m│\u{1b}[0m\u{1b}[31m╰─╯\u{1b}[0m\u{1b}[34m│\u{1b}[0m\n\u{1b}[34m└───┘\u{1b}[0m"
+    );
+}
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn test_fill_pattern_stripes() {
+    let txtframe = TextFrame::new()
+        .fill("╲··")
+        .fill_pattern(FillPattern::Stripes)
+        .width(8)
+        .height(5)
+        .algn(Algn::Centr);
+    let txtframe_iter = txtframe.frame_iter("ab");
+
+    assert_eq!(
+        &txtframe_iter.collect::<String>(),
+        "┌──────┐\n│╲·ab··│\n│·╲··╲·│\n│··╲··╲│\n└──────┘"
+    );
+}
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn test_fill_pattern_repeat_wide() {
+    let txtframe = TextFrame::new()
+        .fill("🟦🟥")
+        .fill_pattern(FillPattern::Repeat)
+        .width(8)
+        .expand_height(1);
+    let txtframe_iter = txtframe.frame_iter("abc");
+
+    assert_eq!(
+        &txtframe_iter.collect::<String>(),
+        "┌──────┐\n│ 🟥🟦 │\n│abc🟦 │\n│ 🟥🟦 │\n└──────┘"
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_test_fill_pattern() {
+    let txtframe: TextFrame = serde_json::from_str(r#"{"fill":"░▒","fill_pattern":"checker"}"#).unwrap();
+
+    assert_eq!(txtframe.get_fill_pattern(), FillPattern::Checker);
+}
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn test_default_frame_shadow_left_top() {
+    let txtframe = TextFrame::new().shadow(Shadow::new().glyph('▒').dir(ShadowDir::LeftTop));
+    let txtframe_iter = txtframe.frame_iter("ab");
+
+    assert_eq!(
+        &txtframe_iter.collect::<String>(),
+        "▒▒▒▒\n▒┌──┐\n▒│ab│\n └──┘"
+    );
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn test_default_frame_shadow_bg() {
+    let txtframe = TextFrame::new()
+        .sides(Sides::NONE)
+        .shadow(Shadow::bg(Color::Black));
+    let txtframe_iter = txtframe.frame_iter("ab\ncd");
+
+    assert_eq!(
+        &txtframe_iter.collect::<String>(),
+        "\u{1b}[0m\u{1b}[0mab\u{1b}[0m\u{1b}[0m\n\u{1b}[0m\u{1b}[0mcd\u{1b}[0m\u{1b}[0m\u{1b}[40m \u{1b}[0m\n \u{1b}[40m  \u{1b}[0m"
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_test_shadow() {
+    let txtframe: TextFrame =
+        serde_json::from_str(r#"{"shadow":{"glyph":"▓","dir":"left_btm"}}"#).unwrap();
+    let shadow = txtframe.get_shadow().unwrap();
+
+    assert_eq!(shadow.get_glyph(), "▓");
+    assert_eq!(shadow.get_dir(), ShadowDir::LeftBtm);
+}
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn test_default_frame_display() {
+    let txtframe = TextFrame::new().algn(Algn::Right);
+
+    assert_eq!(format!("{}", txtframe.display("ab")), "┌──┐\n│ab│\n└──┘");
+    assert_eq!(format!("{:^8}", txtframe.display("ab")), "┌──────┐\n│  ab  │\n└──────┘");
+    assert_eq!(format!("{:<}", txtframe.display("ab\nc")), "┌──┐\n│ab│\n│c │\n└──┘");
+    assert_eq!(format!("{:6}", txtframe.display("ab")), "┌────┐\n│  ab│\n└────┘");
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn test_default_frame_display_iter() {
+    let txtframe = TextFrame::new().color_fra(Color::Red);
+
+    assert_eq!(
+        format!("{:^10}", txtframe.display("ab")),
+        txtframe.clone().width(10).algn(Algn::Centr).frame_iter("ab").collect::<String>()
+    );
+}
+
+#[test]
+fn test_default_frame_write_to() {
+    let txtframe = TextFrame::new().fill('░').width(3000).expand_height(2);
+    let text = "ab\ncd";
+    let frame = txtframe.frame_iter(text).collect::<String>();
+
+    let mut out = Vec::new();
+    assert_eq!(txtframe.write_to(text, &mut out).unwrap(), frame.len());
+    assert_eq!(String::from_utf8(out).unwrap(), frame);
+    assert_eq!(txtframe.byte_len(text), frame.len());
+
+    let mut out = String::new();
+    txtframe.write_to_fmt(text, &mut out).unwrap();
+    assert_eq!(out, frame);
+}
+
+#[test]
+fn test_default_frame_write_to_fmt_batched() {
+    /// Counts the calls to `write_str`.
+    struct CountWrites(String, usize);
+
+    impl std::fmt::Write for CountWrites {
+        fn write_str(&mut self, text: &str) -> std::fmt::Result {
+            self.0.push_str(text);
+            self.1 += 1;
+            Ok(())
+        }
+    }
+
+    let txtframe = TextFrame::new().width(2000);
+    let frame = txtframe.frame_iter("ab").collect::<String>();
+    let mut out = CountWrites(String::new(), 0);
+
+    txtframe.write_to_fmt("ab", &mut out).unwrap();
+    assert_eq!(out.0, frame);
+    assert!(out.1 <= frame.len() / 4096 + 1);
+}
+
+#[test]
+fn test_default_frame_byte_len_styles() {
+    let long_line = "x".repeat(5000);
+    let frames = [
+        TextFrame::new()
+            .fill("░▒")
+            .fill_pattern(FillPattern::Checker)
+            .top_line("─·")
+            .line_pattern(LinePattern::Mirror)
+            .ring(Ring::new(&FrameVar::Ascii.into()).gap(1))
+            .shadow(Shadow::new().dir(ShadowDir::LeftTop))
+            .width(13),
+        TextFrame::new().top_line("<>").vert_left("<=").expand(1),
+    ];
+
+    for txtframe in frames {
+        #[cfg(feature = "color")]
+        let txtframe = txtframe.highlight("b", Color::Red).attr_txt(Attr::BOLD);
+        for text in ["ab\ncbd", &long_line] {
+            let frame = txtframe.frame_iter(text).collect::<String>();
+            let mut out = Vec::new();
+
+            assert_eq!(txtframe.byte_len(text), frame.len());
+            assert_eq!(txtframe.write_to(text, &mut out).unwrap(), frame.len());
+            assert_eq!(String::from_utf8(out).unwrap(), frame);
+        }
+    }
+}
+
+#[test]
+fn test_default_frame_write_to_error() {
+    let txtframe = TextFrame::new();
+    let mut out = [0u8; 4];
+
+    let err = txtframe.write_to("ab", &mut &mut out[..]).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::WriteZero);
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn test_default_frame_lines() {
+    let txtframe = TextFrame::new().color_fra(Color::Red).shadow(Shadow::new());
+    let lines = txtframe.frame_lines("ab").collect::<Vec<_>>();
+    let frame = txtframe.frame_iter("ab").collect::<String>();
+
+    assert_eq!(lines.iter().map(FrameLine::as_str).collect::<Vec<_>>().join("\n"), frame);
+    assert_eq!(lines[0].as_str(), "\u{1b}[31m┌──┐\u{1b}[0m");
+    assert_eq!(
+        lines.iter().map(FrameLine::plain).collect::<Vec<_>>(),
+        ["┌──┐", "│ab│░", "└──┘░", " ░░░░"]
+    );
+    assert_eq!(lines.iter().map(FrameLine::width).collect::<Vec<_>>(), [4, 5, 5, 5]);
+}
+
+#[cfg(feature = "esc")]
+#[test]
+fn test_default_frame_lines_esc() {
+    let txtframe = TextFrame::new();
+    let lines = txtframe.frame_lines_esc("\u{1b}[1mab").collect::<Vec<_>>();
+
+    assert_eq!(lines[1].plain(), "│ab│");
+    assert_eq!(lines[1].width(), 4);
+}
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn test_default_frame_measure() {
+    let txtframe = TextFrame::new()
+        .ring(Ring::new(&FrameVar::Ascii.into()).gap(1))
+        .shadow(Shadow::new().dir(ShadowDir::LeftTop))
+        .height(9);
+    let size = txtframe.measure("abc");
+    let frame = txtframe.frame_iter("abc").collect::<String>();
+    let rows = frame.lines().collect::<Vec<_>>();
+
+    assert_eq!(size.height, rows.len());
+    assert_eq!(rows.iter().map(|row| row.chars().count()).max(), Some(size.width));
+    assert_eq!(size.inner, FrameRect { x: 4, y: 4, width: 3, height: 3 });
+    assert_eq!(rows[size.inner.y], "░│ |abc| │");
+}
+
+#[cfg(feature = "esc")]
+#[test]
+fn test_default_frame_measure_esc() {
+    let txtframe = TextFrame::new();
+
+    assert_eq!(txtframe.measure_esc("\u{1b}[1mab").width, 4);
+    assert_eq!(txtframe.measure("ab").width, 4);
+}
+
+#[cfg(feature = "color")]
+#[test]
+fn test_default_frame_framed_text() {
+    let txtframe = TextFrame::new().color_txt(Color::Green).algn(Algn::Right);
+    let mut framed = txtframe.framed(String::from("ab"));
+
+    assert_eq!(framed.as_str(), txtframe.frame_iter("ab").collect::<String>());
+    assert_eq!(framed.plain(), "┌──┐\n│ab│\n└──┘");
+
+    framed.set_width(5);
+    assert_eq!(
+        framed.to_string(),
+        txtframe.clone().width(5).frame_iter("ab").collect::<String>()
+    );
+    assert_eq!(framed.plain(), "┌───┐\n│ ab│\n└───┘");
+    assert_eq!(framed.get_text(), "ab");
+    assert_eq!(framed.get_frame().get_width(), 5);
+    assert_eq!(framed.size(), txtframe.clone().width(5).measure("ab"));
+    assert_eq!(framed.rows().count(), framed.size().height);
+    assert_eq!(framed.rows().nth(1).unwrap(), framed.as_str().lines().nth(1).unwrap());
+}
+
+#[test]
+fn test_default_frame_framed_text_esc_in_text() {
+    let framed = TextFrame::new().framed("\u{1b}[1mab\u{1b}[0m");
+
+    assert_eq!(
+        framed.plain_rows().collect::<Vec<_>>(),
+        [
+            "┌──────────┐",
+            "│\u{1b}[1mab\u{1b}[0m│",
+            "└──────────┘"
+        ]
+    );
+    assert_eq!(framed.size().height, 3);
+    assert!(framed
+        .plain_rows()
+        .all(|row| row.chars().count() == framed.size().width));
+}
+
+#[test]
+fn test_default_frame_iter_size_hint() {
+    let txtframe = TextFrame::new()
+        .ring(Ring::new(&FrameVar::Round.into()).gap(1))
+        .shadow(Shadow::new())
+        .height(8);
+    let mut txtframe_iter = txtframe.frame_iter("ab\ncd");
+    let pieces = txtframe_iter.clone().collect::<Vec<_>>();
+
+    for taken in 0..pieces.len() {
+        let (lower, upper) = txtframe_iter.size_hint();
+        assert!(lower <= pieces.len() - taken);
+        assert!(upper.is_none_or(|upper| upper >= pieces.len() - taken));
+        assert!(lower > 0);
+        txtframe_iter.next();
+    }
+    assert_eq!(txtframe_iter.size_hint().0, 0);
+
+    let mut txtframe_iter = txtframe.frame_iter("ab\ncd");
+    for _ in 0..7 {
+        txtframe_iter.next();
+    }
+    assert_eq!(txtframe_iter.clone().collect::<Vec<_>>(), pieces[7..]);
+
+    txtframe_iter.by_ref().for_each(drop);
+    assert_eq!(txtframe_iter.size_hint().0, 0);
+    assert_eq!(txtframe_iter.next(), None);
+    assert_eq!(txtframe_iter.next(), None);
+}
+
+#[test]
+fn test_default_frame_prepare() {
+    let txtframe = TextFrame::new().algn(Algn::Centr).height(6).expand_height(1);
+    let prepared = txtframe.prepare(10);
+    let sized = txtframe.clone().width(10);
+
+    for text in ["", "ab", "ab\ncdef", "wider than the frame"] {
+        assert_eq!(prepared.render(text), sized.frame_iter(text).collect::<String>());
+    }
+
+    let mut out = Vec::new();
+    assert_eq!(prepared.write_to("ab", &mut out).unwrap(), out.len());
+    assert_eq!(prepared.get_frame().get_width(), 10);
+}
+
+#[test]
+fn test_default_frame_prepare_uncached() {
+    let txtframe = TextFrame::new()
+        .vert_left("│┃")
+        .fill("░▒")
+        .fill_pattern(FillPattern::Checker)
+        .height(5);
+    let prepared = txtframe.prepare(8);
+
+    assert_eq!(
+        prepared.render("ab"),
+        txtframe.clone().width(8).frame_iter("ab").collect::<String>()
+    );
+}
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn test_default_frame_stream_truncate() {
+    let txtframe = TextFrame::new().expand(1).algn(Algn::Right);
+    let lines = ["abcdef", "", "日本語"].map(String::from);
+    let rows = txtframe
+        .stream_lines(7, Overflow::Truncate, lines)
+        .collect::<std::io::Result<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(
+        rows,
+        ["┌─────┐", "│     │", "│ abc │", "│     │", "│  日 │", "│     │", "└─────┘"]
+    );
+}
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn test_default_frame_stream_matches_frame_iter() {
+    let txtframe = TextFrame::new()
+        .ring(Ring::new(&FrameVar::Ascii.into()).gap(1))
+        .shadow(Shadow::new())
+        .expand_height(1);
+    let text = "one\ntwo\nthree";
+    let rows = txtframe
+        .stream_reader(13, Overflow::Wrap, text.as_bytes())
+        .collect::<std::io::Result<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(
+        rows.join("\n"),
+        txtframe.clone().width(13).frame_iter(text).collect::<String>()
+    );
+}
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn test_default_frame_stream_top_shadow() {
+    for dir in [ShadowDir::RightTop, ShadowDir::LeftTop] {
+        for sides in [Sides::ALL, Sides::ALL - Sides::BTM] {
+            let txtframe = TextFrame::new()
+                .sides(sides)
+                .shadow(Shadow::new().dir(dir));
+            for text in ["ab", "ab\ncdefgh"] {
+                let rows = txtframe
+                    .stream_reader(6, Overflow::Wrap, text.as_bytes())
+                    .collect::<std::io::Result<Vec<_>>>()
+                    .unwrap();
+                let wrapped = text.replace("cdefgh", "cdef\ngh");
+
+                assert_eq!(
+                    rows.join("\n"),
+                    txtframe.clone().width(6).frame_iter(&wrapped).collect::<String>()
+                );
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn test_default_frame_line_writer_top_shadow() {
+    use std::io::Write;
+
+    let txtframe = TextFrame::new()
+        .sides(Sides::ALL - Sides::BTM)
+        .shadow(Shadow::new().dir(ShadowDir::RightTop));
+    let mut writer = txtframe.line_writer(6, Overflow::Wrap, Vec::new());
+    writeln!(writer, "ab").unwrap();
+    assert_eq!(writer.get_ref(), " ░░░░░░\n┌────┐░\n".as_bytes());
+
+    writeln!(writer, "cd").unwrap();
+    assert_eq!(writer.get_ref(), " ░░░░░░\n┌────┐░\n│ab  │░\n".as_bytes());
+
+    let out = writer.finish().unwrap();
+    let expected = format!("{}\n", txtframe.width(6).frame_iter("ab\ncd").collect::<String>());
+    assert_eq!(String::from_utf8(out).unwrap(), expected);
+}
+
+#[test]
+fn test_default_frame_stream_write_to_error() {
+    let txtframe = TextFrame::new();
+    let stream = txtframe.stream_lines(6, Overflow::Wrap, [String::from("ab")]);
+    let mut out = Vec::new();
+    assert_eq!(stream.write_to(&mut out).unwrap(), out.len());
+
+    let reader = b"ab\n\xff\ncd".as_slice();
+    let mut stream = txtframe.stream_reader(6, Overflow::Wrap, reader);
+    let err = stream.find_map(Result::err).unwrap();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    assert!(stream.next().is_none());
+}
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn test_default_frame_line_writer() {
+    use std::io::Write;
+
+    let txtframe = TextFrame::new();
+    let mut writer = txtframe.line_writer(6, Overflow::Truncate, Vec::new());
+    write!(writer, "ab").unwrap();
+    assert_eq!(writer.get_ref(), "┌────┐\n".as_bytes());
+
+    write!(writer, "c\r\nde\nfghij").unwrap();
+    assert_eq!(writer.get_ref(), "┌────┐\n│abc │\n│de  │\n".as_bytes());
+
+    let out = writer.finish().unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "┌────┐\n│abc │\n│de  │\n│fghi│\n└────┘\n"
+    );
+
+    let mut out = Vec::new();
+    drop(txtframe.line_writer(4, Overflow::Wrap, &mut out));
+    assert_eq!(String::from_utf8(out).unwrap(), "┌──┐\n└──┘\n");
+}
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn test_default_frame_writer() {
+    use std::io::Write;
+
+    let txtframe = TextFrame::new();
+    let mut out = Vec::new();
+    let mut writer = txtframe.writer(&mut out);
+    writeln!(writer, "ab").unwrap();
+    write!(writer, "cde").unwrap();
+    assert!(writer.get_ref().is_empty());
+
+    writer.flush().unwrap();
+    writer.flush().unwrap();
+    write!(writer, "f").unwrap();
+    drop(writer);
+
+    let expected = format!(
+        "{}\n{}\n",
+        txtframe.frame_iter("ab\ncde").collect::<String>(),
+        txtframe.frame_iter("f").collect::<String>()
+    );
+    assert_eq!(String::from_utf8(out).unwrap(), expected);
+}
+
+/// Writer taking up to `budget` bytes, then failing.
+#[cfg(not(feature = "color"))]
+struct FlakyWriter {
+    out: Vec<u8>,
+    budget: usize,
+}
+
+#[cfg(not(feature = "color"))]
+impl std::io::Write for FlakyWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if self.budget == 0 {
+            return Err(std::io::ErrorKind::Other.into());
+        }
+        let len = buf.len().min(self.budget);
+        self.out.extend_from_slice(&buf[..len]);
+        self.budget -= len;
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn test_default_frame_line_writer_errors() {
+    use std::io::Write;
+
+    let txtframe = TextFrame::new();
+    let flaky = FlakyWriter {
+        out: Vec::new(),
+        budget: 6,
+    };
+    let mut writer = txtframe.line_writer(6, Overflow::Wrap, flaky);
+    assert_eq!(writer.write(b"ab\n").unwrap(), 3);
+    assert_eq!(writer.get_ref().out, "┌─".as_bytes());
+
+    assert!(writer.write(b"cd\n").is_err());
+    assert!(writer.flush().is_err());
+
+    writer.get_mut().budget = usize::MAX;
+    assert_eq!(writer.write(b"cd\n").unwrap(), 3);
+    let out = writer.finish().unwrap().out;
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "┌────┐\n│ab  │\n│cd  │\n└────┘\n"
+    );
+}
+
+#[cfg(not(feature = "color"))]
+#[test]
+fn test_default_frame_writer_errors() {
+    use std::io::Write;
+
+    let txtframe = TextFrame::new();
+    let flaky = FlakyWriter {
+        out: Vec::new(),
+        budget: 4,
+    };
+    let mut writer = txtframe.writer(flaky);
+    writeln!(writer, "ab").unwrap();
+    assert!(writer.flush().is_err());
+    assert!(writer.write(b"cd").is_err());
+
+    writer.get_mut().budget = usize::MAX;
+    writer.flush().unwrap();
+    let out = writer.finish().unwrap().out;
+    let expected = format!("{}\n", txtframe.frame_iter("ab").collect::<String>());
+    assert_eq!(String::from_utf8(out).unwrap(), expected);
+}
+
+#[test]
+fn test_frame_macro() {
+    let style = TextFrame::new().algn(Algn::Centr);
+    let (svc, env) = ("api", "prod");
+    let framed = frame!(style, "Deployed {} to {}", svc, env);
+    assert_eq!(framed, style.framed("Deployed api to prod"));
+
+    let framed = frame!(&style, width = 9, algn = Algn::Left, "{svc}",);
+    assert_eq!(framed.plain(), "┌───────┐\n│api    │\n└───────┘");
+    assert_eq!(style.get_algn(), Algn::Centr);
+}
+
+#[test]
+fn test_frame_trait() {
+    let txtframe = TextFrame::new();
+    assert_eq!(42.framed(&txtframe), txtframe.framed("42"));
+    assert_eq!("ab".framed(&txtframe).plain(), "┌──┐\n│ab│\n└──┘");
+}